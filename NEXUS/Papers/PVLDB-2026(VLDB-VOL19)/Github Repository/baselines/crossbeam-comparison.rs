@@ -18,17 +18,242 @@
 //! - Lower synchronization latency as thread count increases
 //! - More consistent garbage collection timing
 //! - Comparable or lower memory overhead
+//!
+//! The `_pin`/`_unpin`/`_advance` row families run under a real
+//! multi-threaded contention workload (see [`run_contention_workload`]):
+//! `thread_count` workers repeatedly pin/unpin while the calling thread
+//! races `try_advance`, so advancement time is actually measured against
+//! concurrent pinners rather than an idle collector. `nexus_*` uses
+//! cache-padded leaf/level1 epoch slots (see
+//! `nexus_baseline::PaddedAtomicU64`); `nexus_unpadded_*` runs the identical
+//! algorithm with every level packed, isolating how much of the improvement
+//! over `crossbeam_*` is attributable to removing false sharing versus the
+//! O(log T) algorithm itself. `memory_overhead_bytes` is read back from each
+//! collector *after* the workload runs (see [`EpochCollector::memory_overhead_bytes`]),
+//! since participant storage now grows on demand instead of being fixed up
+//! front.
+//!
+//! Participants are no longer capped at a hardcoded thread count: see
+//! [`sharded_registry`] for the striped-counter + growable-segment registry
+//! both collectors register through, and [`Participant`] for the RAII guard
+//! that lets worker threads come and go without leaking slots.
+//!
+//! Neither collector used to retire anything — they only modeled
+//! pin/unpin/advance synchronization, not the reclamation work a real
+//! allocator pays for. [`reclaim`] adds a `retire`/`collect_reclaimable`
+//! path to both: retired objects batch into fixed-capacity blocks recycled
+//! through a thread-local free-list (so steady-state retirement doesn't
+//! keep hitting the allocator for bag storage), sealed with the epoch
+//! active when they filled, and destroyed once `global_min()` has passed
+//! that epoch. `main` wraps the global allocator with an atomic counter (see
+//! [`CountingAllocator`]) so [`allocation_accounting`] can report real
+//! allocations-per-reclaimed-object.
+//!
+//! `try_advance` is meant to be polled from a hot loop, but the coordinator
+//! in [`run_contention_workload`] busy-spins it unconditionally, which is
+//! unrealistic for a thread that isn't also doing useful work. [`collector_daemon`]
+//! adds a background collector that backs off instead: it spins a handful of
+//! times, then yields a handful of times, then parks with a bounded,
+//! doubling timeout, looping until told to stop. Pinning threads that
+//! observe (via their own `try_advance` call right after unpinning) that
+//! they were the last laggard unpark the daemon immediately rather than
+//! making it wait out its backoff. [`run_daemon_contention_workload`] drives
+//! this variant under the same workload as the spin loop and every
+//! `try_advance` attempt the daemon makes is timed, landing in a
+//! `{prefix}_daemon_advance` row next to the existing `{prefix}_advance` row
+//! so the two approaches are directly comparable.
+//!
+//! Every latency used to accumulate into a per-configuration `Vec<f64>`,
+//! sorted and indexed by `len() * quantile` to read off a percentile — O(N
+//! log N) time and O(N) memory that grows with `BENCHMARK_ITERATIONS`.
+//! [`histogram::Histogram`] replaces that with a fixed-memory logarithmic
+//! bucket histogram in the HdrHistogram style: the first [`histogram::LINEAR_BUCKETS`]
+//! nanoseconds get one bucket each, and every doubling of the range above
+//! that ("octave") is subdivided into [`histogram::SUB_BUCKETS_PER_OCTAVE`]
+//! equal-width buckets, so resolution scales with magnitude instead of
+//! staying fixed. `record` increments one bucket's count in O(1); `quantile`
+//! walks the (small, fixed-size) bucket array accumulating counts until it
+//! crosses `q * total`, recovering p50/p99/p999/p9999 and the true max
+//! without ever sorting or storing a sample. `BenchmarkResult` now also
+//! carries `p9999_latency_ns` and `max_latency_ns`, cheap to expose now that
+//! nothing needs a full sorted sample array to compute them.
+//!
+//! `main` also persists a baseline: before overwriting `crossbeam_comparison.csv`
+//! it loads whatever that file already held from the *previous* run (see
+//! [`load_baseline`]) and diffs every new row's p99 against its baseline
+//! counterpart (same name and thread count). A p99 more than
+//! `REGRESSION_THRESHOLD_PCT` worse than its baseline (overridable via the
+//! `NEXUS_BENCH_REGRESSION_PCT` environment variable) is reported as a
+//! regression; if any are found, `main` prints the diff report and exits
+//! with a non-zero status so the sweep can gate CI. Since a full
+//! `THREAD_COUNTS` sweep can run for a while, [`run_benchmarks`] also prints
+//! a progress line before each thread count with how many configurations
+//! are done, elapsed time, and a linear ETA to the end of the sweep, so a
+//! slow 128-thread configuration doesn't look hung.
 
-use std::sync::atomic::{AtomicU64, Ordering};
-use std::sync::Arc;
+use std::cell::UnsafeCell;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Barrier, Mutex};
 use std::time::{Duration, Instant};
 use std::thread;
+use std::mem;
 
 /// Configuration for benchmarks
 const WARMUP_ITERATIONS: usize = 1000;
 const BENCHMARK_ITERATIONS: usize = 10000;
 const THREAD_COUNTS: &[usize] = &[1, 2, 4, 8, 16, 32, 64, 128];
 
+/// Fixed-memory logarithmic bucket histogram backing [`BenchmarkResult`]'s
+/// percentile fields. See the module docs for the rationale.
+mod histogram {
+    /// Latencies below this many nanoseconds get their own exact, linear
+    /// bucket. Must be a power of two: it also sets the width of octave 0
+    /// below.
+    pub(crate) const LINEAR_BUCKETS: usize = 1024;
+
+    /// Number of equal-width buckets each doubling of range ("octave")
+    /// above `LINEAR_BUCKETS` is divided into. `LINEAR_BUCKETS` must be a
+    /// multiple of this, so every octave's width divides evenly.
+    pub(crate) const SUB_BUCKETS_PER_OCTAVE: usize = 64;
+
+    /// Number of octaves tracked above `LINEAR_BUCKETS`. Values at or past
+    /// `LINEAR_BUCKETS << MAX_OCTAVES` nanoseconds (about 19 minutes, for
+    /// the constants above) land in the overflow bucket instead of
+    /// panicking or growing the histogram.
+    pub(crate) const MAX_OCTAVES: usize = 32;
+
+    const OVERFLOW_BUCKET: usize = LINEAR_BUCKETS + MAX_OCTAVES * SUB_BUCKETS_PER_OCTAVE;
+    const TOTAL_BUCKETS: usize = OVERFLOW_BUCKET + 1;
+
+    /// A fixed-size logarithmic bucket histogram: recording a value is O(1)
+    /// and never allocates, and the whole thing occupies
+    /// `TOTAL_BUCKETS * 8` bytes regardless of how many values are recorded
+    /// — unlike a `Vec<f64>` of samples, which grows with every `record`.
+    pub(crate) struct Histogram {
+        counts: Box<[u64; TOTAL_BUCKETS]>,
+        count: u64,
+        sum_ns: f64,
+        max_ns: u64,
+    }
+
+    impl Histogram {
+        pub(crate) fn new() -> Self {
+            Self {
+                counts: Box::new([0u64; TOTAL_BUCKETS]),
+                count: 0,
+                sum_ns: 0.0,
+                max_ns: 0,
+            }
+        }
+
+        /// Maps a nanosecond value to its bucket: the first `LINEAR_BUCKETS`
+        /// values get one bucket each; above that, the octave is found from
+        /// the value's highest set bit and subdivided linearly into
+        /// `SUB_BUCKETS_PER_OCTAVE` equal-width slots.
+        fn bucket_of(value_ns: u64) -> usize {
+            if (value_ns as usize) < LINEAR_BUCKETS {
+                return value_ns as usize;
+            }
+
+            // `value_ns >= LINEAR_BUCKETS`, and `LINEAR_BUCKETS` is a power
+            // of two, so `octave` counts how many doublings past
+            // `LINEAR_BUCKETS` the value's leading bit puts it.
+            let log2_value = 63 - value_ns.leading_zeros() as usize;
+            let log2_linear = LINEAR_BUCKETS.trailing_zeros() as usize;
+            let octave = log2_value - log2_linear;
+
+            if octave >= MAX_OCTAVES {
+                return OVERFLOW_BUCKET;
+            }
+
+            let octave_start = (LINEAR_BUCKETS as u64) << octave;
+            let octave_width = octave_start;
+            let sub_bucket_width = octave_width / SUB_BUCKETS_PER_OCTAVE as u64;
+            let sub_index = ((value_ns - octave_start) / sub_bucket_width) as usize;
+
+            LINEAR_BUCKETS + octave * SUB_BUCKETS_PER_OCTAVE + sub_index.min(SUB_BUCKETS_PER_OCTAVE - 1)
+        }
+
+        /// Records one latency sample, in nanoseconds.
+        pub(crate) fn record(&mut self, value_ns: f64) {
+            let value_ns = value_ns.max(0.0) as u64;
+            self.counts[Self::bucket_of(value_ns)] += 1;
+            self.count += 1;
+            self.sum_ns += value_ns as f64;
+            self.max_ns = self.max_ns.max(value_ns);
+        }
+
+        /// Folds `other`'s counts into `self`, for merging per-thread
+        /// histograms into one overall distribution.
+        pub(crate) fn merge(&mut self, other: &Histogram) {
+            for (mine, theirs) in self.counts.iter_mut().zip(other.counts.iter()) {
+                *mine += theirs;
+            }
+            self.count += other.count;
+            self.sum_ns += other.sum_ns;
+            self.max_ns = self.max_ns.max(other.max_ns);
+        }
+
+        pub(crate) fn count(&self) -> u64 {
+            self.count
+        }
+
+        pub(crate) fn mean(&self) -> f64 {
+            if self.count == 0 { 0.0 } else { self.sum_ns / self.count as f64 }
+        }
+
+        pub(crate) fn max(&self) -> f64 {
+            self.max_ns as f64
+        }
+
+        /// Returns (the upper edge of the bucket holding) the smallest
+        /// value at or past the `q` quantile, walking buckets from the
+        /// bottom and accumulating counts until the running total crosses
+        /// `q * count()`. `q` is clamped to `[0, 1]`.
+        pub(crate) fn quantile(&self, q: f64) -> f64 {
+            if self.count == 0 {
+                return 0.0;
+            }
+
+            let target = (q.clamp(0.0, 1.0) * self.count as f64).ceil().max(1.0) as u64;
+            let mut cumulative = 0u64;
+
+            for (bucket, &bucket_count) in self.counts.iter().enumerate() {
+                if bucket_count == 0 {
+                    continue;
+                }
+                cumulative += bucket_count;
+                if cumulative >= target {
+                    return Self::bucket_upper_bound(bucket) as f64;
+                }
+            }
+
+            self.max_ns as f64
+        }
+
+        /// The largest nanosecond value that still maps into `bucket`.
+        fn bucket_upper_bound(bucket: usize) -> u64 {
+            if bucket < LINEAR_BUCKETS {
+                return bucket as u64;
+            }
+            if bucket >= OVERFLOW_BUCKET {
+                return u64::MAX;
+            }
+
+            let within_octaves = bucket - LINEAR_BUCKETS;
+            let octave = within_octaves / SUB_BUCKETS_PER_OCTAVE;
+            let sub_index = within_octaves % SUB_BUCKETS_PER_OCTAVE;
+
+            let octave_start = (LINEAR_BUCKETS as u64) << octave;
+            let sub_bucket_width = octave_start / SUB_BUCKETS_PER_OCTAVE as u64;
+            octave_start + (sub_index as u64 + 1) * sub_bucket_width - 1
+        }
+    }
+}
+
+use histogram::Histogram;
+
 /// Results from a benchmark run
 #[derive(Debug, Clone)]
 pub struct BenchmarkResult {
@@ -38,88 +263,540 @@ pub struct BenchmarkResult {
     pub p50_latency_ns: f64,
     pub p99_latency_ns: f64,
     pub p999_latency_ns: f64,
+    /// 99.99th percentile latency — only cheap to carry now that
+    /// [`Histogram::quantile`] doesn't need a full sorted sample array to
+    /// recover it.
+    pub p9999_latency_ns: f64,
+    /// The single slowest sample observed for this configuration.
+    pub max_latency_ns: f64,
     pub throughput_ops_per_sec: f64,
+    /// Bytes resident for this collector's epoch-slot storage at the moment
+    /// the row was recorded — now a live readback (slots are allocated on
+    /// demand) rather than a fixed constant, see
+    /// [`EpochCollector::memory_overhead_bytes`].
+    pub memory_overhead_bytes: usize,
 }
 
 impl BenchmarkResult {
     pub fn to_csv_row(&self) -> String {
         format!(
-            "{},{},{:.2},{:.2},{:.2},{:.2},{:.2}",
+            "{},{},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{:.2},{}",
             self.name,
             self.thread_count,
             self.mean_latency_ns,
             self.p50_latency_ns,
             self.p99_latency_ns,
             self.p999_latency_ns,
-            self.throughput_ops_per_sec
+            self.p9999_latency_ns,
+            self.max_latency_ns,
+            self.throughput_ops_per_sec,
+            self.memory_overhead_bytes
         )
     }
+
+    /// Parses a row written by [`to_csv_row`](Self::to_csv_row). Returns
+    /// `None` on any malformed field rather than panicking, so a corrupt or
+    /// hand-edited baseline file just gets skipped instead of crashing the
+    /// whole sweep.
+    pub fn from_csv_row(row: &str) -> Option<Self> {
+        let mut fields = row.split(',');
+        Some(Self {
+            name: fields.next()?.to_string(),
+            thread_count: fields.next()?.parse().ok()?,
+            mean_latency_ns: fields.next()?.parse().ok()?,
+            p50_latency_ns: fields.next()?.parse().ok()?,
+            p99_latency_ns: fields.next()?.parse().ok()?,
+            p999_latency_ns: fields.next()?.parse().ok()?,
+            p9999_latency_ns: fields.next()?.parse().ok()?,
+            max_latency_ns: fields.next()?.parse().ok()?,
+            throughput_ops_per_sec: fields.next()?.parse().ok()?,
+            memory_overhead_bytes: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+/// Block-cached deferred-reclamation primitives shared by every collector
+/// variant below. Retired objects batch into fixed-capacity [`Block`]s
+/// recycled through a thread-local free-list rather than allocated fresh
+/// per retirement, sealed with the epoch active when they filled, and
+/// reclaimed once the collector's `global_min()` has passed that epoch.
+///
+/// For `nexus_baseline`, this inherits `GrowableLevels`'s existing
+/// accepted imprecision: a tier rebuild (triggered by registry growth)
+/// resets every slot to `INACTIVE` until the still-pinned participants
+/// it affects next pin/unpin, so `global_min()` can briefly read as if
+/// nobody is pinned. That's already documented there as an acceptable
+/// tradeoff for a baseline harness; it now also means reclamation can run
+/// ahead of a stale-but-still-pinned participant in that same narrow
+/// window, same as any other consumer of `global_min()`.
+mod reclaim {
+    use std::cell::RefCell;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Mutex;
+
+    /// Entries held per garbage block before it seals and hands off to the
+    /// collector's reclamation queue.
+    pub(crate) const BLOCK_CAPACITY: usize = 32;
+
+    /// A single retired object: its destructor, already bound to its
+    /// pointer, boxed so a [`Block`] can hold entries of differing `T`
+    /// without itself being generic.
+    pub(crate) struct Retired(Box<dyn FnOnce() + Send>);
+
+    impl Retired {
+        /// Wraps `ptr` for destruction via `dtor` once it's safe to
+        /// reclaim.
+        ///
+        /// # Safety
+        ///
+        /// `ptr` must be valid and not destroyed by any other means before
+        /// the returned `Retired` runs.
+        pub(crate) unsafe fn new<T: 'static>(ptr: *mut T, dtor: unsafe fn(*mut T)) -> Self {
+            let addr = ptr as usize;
+            Self(Box::new(move || unsafe { dtor(addr as *mut T) }))
+        }
+    }
+
+    /// A fixed-capacity batch of retired entries. Recycled through the
+    /// thread-local free-list below instead of allocated fresh per
+    /// retirement, so a steady-state workload that retires and reclaims at
+    /// roughly the same rate never grows its `Vec` past `BLOCK_CAPACITY`
+    /// nor reallocates one.
+    pub(crate) struct Block {
+        entries: Vec<Retired>,
+    }
+
+    impl Block {
+        fn new() -> Self {
+            Self { entries: Vec::with_capacity(BLOCK_CAPACITY) }
+        }
+
+        pub(crate) fn push(&mut self, entry: Retired) {
+            self.entries.push(entry);
+        }
+
+        pub(crate) fn is_full(&self) -> bool {
+            self.entries.len() >= BLOCK_CAPACITY
+        }
+
+        pub(crate) fn len(&self) -> usize {
+            self.entries.len()
+        }
+
+        /// Runs every entry's destructor, leaving the block empty (but
+        /// still holding its `BLOCK_CAPACITY` allocation) for recycling.
+        fn run_and_clear(&mut self) {
+            for entry in self.entries.drain(..) {
+                (entry.0)();
+            }
+        }
+    }
+
+    thread_local! {
+        /// Empty blocks returned by [`recycle_block`], preferred by
+        /// [`take_block`] over allocating a fresh `Vec`.
+        static FREE_BLOCKS: RefCell<Vec<Block>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Blocks minted via a fresh allocation rather than served from
+    /// `FREE_BLOCKS` — read by `allocation_accounting` alongside the global
+    /// allocator's own counter to show how quickly the cache warms up.
+    pub(crate) static BLOCKS_ALLOCATED: AtomicU64 = AtomicU64::new(0);
+
+    /// Takes a recycled empty block from this thread's free-list, falling
+    /// back to a fresh allocation only when the list is empty.
+    pub(crate) fn take_block() -> Block {
+        FREE_BLOCKS.with(|cache| cache.borrow_mut().pop()).unwrap_or_else(|| {
+            BLOCKS_ALLOCATED.fetch_add(1, Ordering::Relaxed);
+            Block::new()
+        })
+    }
+
+    /// Returns an already-emptied block to this thread's free-list.
+    pub(crate) fn recycle_block(block: Block) {
+        FREE_BLOCKS.with(|cache| cache.borrow_mut().push(block));
+    }
+
+    /// A sealed block awaiting collection, stamped with the epoch active
+    /// when it filled.
+    pub(crate) struct SealedBag {
+        pub(crate) epoch: u64,
+        pub(crate) block: Block,
+    }
+
+    /// Drains every bag in `sealed` whose epoch has been passed by
+    /// `global_min`, running its destructors and recycling its block, and
+    /// returns how many objects were reclaimed. Shared by every collector
+    /// variant so the scan/seal bookkeeping isn't duplicated per type.
+    pub(crate) fn collect_reclaimable(sealed: &Mutex<Vec<SealedBag>>, global_min: u64) -> usize {
+        let mut sealed = sealed.lock().expect("sealed bag queue poisoned");
+        let mut reclaimed = 0;
+        let mut i = 0;
+        while i < sealed.len() {
+            // A bag sealed during epoch `e` may still be observed by a
+            // participant that hasn't yet advanced past `e`; only once the
+            // global minimum has moved strictly past it is every reader
+            // guaranteed gone.
+            if sealed[i].epoch < global_min {
+                let mut bag = sealed.swap_remove(i);
+                reclaimed += bag.block.len();
+                bag.block.run_and_clear();
+                recycle_block(bag.block);
+            } else {
+                i += 1;
+            }
+        }
+        reclaimed
+    }
+}
+
+/// Sharded, growable participant registry shared by both baselines below.
+///
+/// Both `crossbeam_baseline` and `nexus_baseline` used to hand out
+/// participant ids from a single `AtomicU64::fetch_add`, capped at a
+/// hardcoded `MAX_PARTICIPANTS = 256` — itself a contention point (every
+/// `register()` serializes on the same cache line) and a hard ceiling that
+/// panics a long-running inference server the moment its 257th worker
+/// thread shows up. This module replaces both problems: [`ShardedCounter`]
+/// stripes id allocation across `N_SHARDS` independent counters chosen by
+/// hashing the registering thread's [`ThreadId`](std::thread::ThreadId), and
+/// [`SlotStore`] backs the actual epoch slots with a `Vec` of fixed-size
+/// segments grown lazily as ids are handed out past the current capacity.
+mod sharded_registry {
+    use super::*;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    use std::sync::{Mutex, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+    /// Number of independent counter stripes id allocation is spread over.
+    pub(crate) const N_SHARDS: usize = 16;
+    /// Slots per lazily-allocated segment.
+    pub(crate) const SEGMENT_SIZE: usize = 64;
+
+    /// Common surface `AtomicU64` and `PaddedAtomicU64` both satisfy, so
+    /// [`SlotStore`] and [`Levels`] can be generic over the padded or
+    /// unpadded slot representation instead of duplicating this module for
+    /// `nexus_baseline::HierarchicalEpochCollectorUnpadded`.
+    pub(crate) trait EpochSlot: Send + Sync {
+        fn new(value: u64) -> Self;
+        fn load(&self, order: Ordering) -> u64;
+        fn store(&self, value: u64, order: Ordering);
+    }
+
+    impl EpochSlot for AtomicU64 {
+        fn new(value: u64) -> Self {
+            AtomicU64::new(value)
+        }
+        fn load(&self, order: Ordering) -> u64 {
+            AtomicU64::load(self, order)
+        }
+        fn store(&self, value: u64, order: Ordering) {
+            AtomicU64::store(self, value, order)
+        }
+    }
+
+    /// Minimal stand-in for `crossbeam::sync::ShardedLock`, built on
+    /// `std::sync::RwLock` rather than pulling in the dependency — this
+    /// file simulates the concepts under comparison rather than importing
+    /// the real crates (see `crossbeam_baseline`/`nexus_baseline` below, and
+    /// can't compile the host library's own `arc_swap`-based tree growth
+    /// either). Reads are the common case (`pin`/`unpin` looking up a slot
+    /// that already exists) and never block each other; only growing the
+    /// segment `Vec` past its current length takes the exclusive side.
+    pub(crate) struct ShardedLock<T> {
+        inner: RwLock<T>,
+    }
+
+    impl<T> ShardedLock<T> {
+        fn new(value: T) -> Self {
+            Self { inner: RwLock::new(value) }
+        }
+
+        fn read(&self) -> RwLockReadGuard<'_, T> {
+            self.inner.read().expect("sharded lock poisoned")
+        }
+
+        fn write(&self) -> RwLockWriteGuard<'_, T> {
+            self.inner.write().expect("sharded lock poisoned")
+        }
+    }
+
+    /// Striped id allocator: each of `N_SHARDS` stripes owns an independent
+    /// `AtomicU64` counter, so concurrently registering threads rarely
+    /// contend on the same cache line the way a single shared counter does.
+    /// A thread picks its stripe by hashing `thread::current().id()`; the
+    /// id it receives is `stripe + n * N_SHARDS` for the nth id that stripe
+    /// has handed out, which keeps ids dense enough to pack tightly into
+    /// [`SlotStore`] segments.
+    pub(crate) struct ShardedCounter {
+        shards: [AtomicU64; N_SHARDS],
+    }
+
+    impl ShardedCounter {
+        pub(crate) fn new() -> Self {
+            Self { shards: std::array::from_fn(|_| AtomicU64::new(0)) }
+        }
+
+        fn stripe() -> usize {
+            let mut hasher = DefaultHasher::new();
+            thread::current().id().hash(&mut hasher);
+            (hasher.finish() as usize) % N_SHARDS
+        }
+
+        pub(crate) fn next(&self) -> usize {
+            let stripe = Self::stripe();
+            let n = self.shards[stripe].fetch_add(1, Ordering::Relaxed) as usize;
+            stripe + n * N_SHARDS
+        }
+    }
+
+    /// Growable backing store for per-participant epoch slots: a `Vec` of
+    /// fixed-size segments allocated lazily as [`acquire`](Self::acquire)
+    /// hands out ids past the current high-water mark. Freed ids (from a
+    /// dropped [`Participant`](super::Participant)) are reused before a new
+    /// id is minted, so a churning pool of worker threads doesn't grow the
+    /// store without bound.
+    pub(crate) struct SlotStore<T: EpochSlot> {
+        segments: ShardedLock<Vec<Arc<[T; SEGMENT_SIZE]>>>,
+        counter: ShardedCounter,
+        free_list: Mutex<Vec<usize>>,
+        make_slot: fn() -> T,
+    }
+
+    impl<T: EpochSlot> SlotStore<T> {
+        pub(crate) fn new(make_slot: fn() -> T) -> Self {
+            Self {
+                segments: ShardedLock::new(Vec::new()),
+                counter: ShardedCounter::new(),
+                free_list: Mutex::new(Vec::new()),
+                make_slot,
+            }
+        }
+
+        /// Allocates a slot id, preferring one returned to the free list
+        /// over minting a new one, growing the segment `Vec` under the
+        /// write side of the lock if the id falls past current capacity.
+        pub(crate) fn acquire(&self) -> usize {
+            if let Some(id) = self.free_list.lock().expect("free list poisoned").pop() {
+                return id;
+            }
+            let id = self.counter.next();
+            self.ensure_capacity(id);
+            id
+        }
+
+        /// Returns `id` to the free list for reuse by a future `acquire()`.
+        pub(crate) fn release(&self, id: usize) {
+            self.free_list.lock().expect("free list poisoned").push(id);
+        }
+
+        fn ensure_capacity(&self, id: usize) {
+            let segment_idx = id / SEGMENT_SIZE;
+            if segment_idx < self.segments.read().len() {
+                return;
+            }
+            let mut segments = self.segments.write();
+            while segment_idx >= segments.len() {
+                segments.push(Arc::new(std::array::from_fn(|_| (self.make_slot)())));
+            }
+        }
+
+        /// Current capacity in slots, rounded up to a whole segment.
+        pub(crate) fn capacity(&self) -> usize {
+            self.segments.read().len() * SEGMENT_SIZE
+        }
+
+        /// Returns the segment containing `id`, cloning the `Arc` under the
+        /// read side of the lock so the actual load/store happens lock-free.
+        pub(crate) fn segment(&self, id: usize) -> Arc<[T; SEGMENT_SIZE]> {
+            Arc::clone(&self.segments.read()[id / SEGMENT_SIZE])
+        }
+
+        pub(crate) fn load(&self, id: usize, order: Ordering) -> u64 {
+            self.segment(id)[id % SEGMENT_SIZE].load(order)
+        }
+
+        pub(crate) fn store(&self, id: usize, value: u64, order: Ordering) {
+            self.segment(id)[id % SEGMENT_SIZE].store(value, order)
+        }
+    }
+
+    /// Hierarchical aggregation tiers sized to cover a given leaf capacity.
+    /// `tiers[0]` aggregates groups of `BRANCHING_FACTOR` leaves, `tiers[1]`
+    /// aggregates groups of `tiers[0]`, and so on; `tiers.last()` is the
+    /// root `try_advance` reads. Depth is derived at runtime as
+    /// `ceil(log_branching(leaf_capacity))` rather than fixed at four
+    /// levels, so it tracks however large the registry has grown.
+    pub(crate) struct Levels<T: EpochSlot> {
+        pub(crate) tiers: Vec<Box<[T]>>,
+    }
+
+    impl<T: EpochSlot> Levels<T> {
+        pub(crate) fn for_leaf_capacity(branching_factor: usize, leaf_capacity: usize) -> Self {
+            let mut tiers = Vec::new();
+            let mut size = leaf_capacity.max(1);
+            while size > 1 {
+                size = (size + branching_factor - 1) / branching_factor;
+                tiers.push((0..size).map(|_| T::new(u64::MAX)).collect());
+            }
+            if tiers.is_empty() {
+                tiers.push(vec![T::new(u64::MAX)].into_boxed_slice());
+            }
+            Self { tiers }
+        }
+
+        /// Whether the current tiers still cover `leaf_capacity` leaves.
+        pub(crate) fn covers(&self, branching_factor: usize, leaf_capacity: usize) -> bool {
+            self.tiers
+                .first()
+                .map(|level1| level1.len() * branching_factor >= leaf_capacity)
+                .unwrap_or(leaf_capacity == 0)
+        }
+    }
+
+    /// Guards `store` with a [`ShardedLock`], rebuilding its [`Levels`] from
+    /// scratch whenever `leaf_capacity` outgrows what the current tiers
+    /// cover. Rebuilding resets every tier to `INACTIVE`: a participant
+    /// registered before the rebuild re-publishes its real epoch into the
+    /// new tiers on its next `pin`/`unpin`, so `try_advance` briefly sees a
+    /// stale (conservative) view of a just-grown tree rather than a wrong
+    /// one — an acceptable tradeoff for a baseline comparison harness.
+    pub(crate) struct GrowableLevels<T: EpochSlot> {
+        levels: ShardedLock<Levels<T>>,
+        branching_factor: usize,
+    }
+
+    impl<T: EpochSlot> GrowableLevels<T> {
+        pub(crate) fn new(branching_factor: usize) -> Self {
+            Self {
+                levels: ShardedLock::new(Levels::for_leaf_capacity(branching_factor, 0)),
+                branching_factor,
+            }
+        }
+
+        pub(crate) fn ensure_capacity(&self, leaf_capacity: usize) {
+            if self.levels.read().covers(self.branching_factor, leaf_capacity) {
+                return;
+            }
+            let mut levels = self.levels.write();
+            if !levels.covers(self.branching_factor, leaf_capacity) {
+                *levels = Levels::for_leaf_capacity(self.branching_factor, leaf_capacity);
+            }
+        }
+
+        pub(crate) fn read(&self) -> RwLockReadGuard<'_, Levels<T>> {
+            self.levels.read()
+        }
+
+        pub(crate) fn branching_factor(&self) -> usize {
+            self.branching_factor
+        }
+    }
 }
 
 /// Simulated Crossbeam-style flat epoch implementation for comparison
 mod crossbeam_baseline {
+    use super::sharded_registry::SlotStore;
     use super::*;
-    
-    const MAX_PARTICIPANTS: usize = 256;
+
     const INACTIVE: u64 = u64::MAX;
-    
-    /// Flat epoch collector (Crossbeam-style)
+
+    /// Flat epoch collector (Crossbeam-style). Epoch slots live in a
+    /// [`SlotStore`] that grows on demand instead of a fixed
+    /// `MAX_PARTICIPANTS`-sized array, so registering past the old 256
+    /// cap no longer panics.
     pub struct FlatEpochCollector {
         global_epoch: AtomicU64,
-        participants: Box<[AtomicU64; MAX_PARTICIPANTS]>,
-        num_participants: AtomicU64,
+        slots: SlotStore<AtomicU64>,
+        /// Sealed garbage blocks awaiting reclamation — see the `reclaim`
+        /// module docs.
+        sealed: Mutex<Vec<reclaim::SealedBag>>,
     }
-    
+
     impl FlatEpochCollector {
         pub fn new() -> Self {
-            let participants = Box::new([(); MAX_PARTICIPANTS].map(|_| AtomicU64::new(INACTIVE)));
             Self {
                 global_epoch: AtomicU64::new(0),
-                participants,
-                num_participants: AtomicU64::new(0),
+                slots: SlotStore::new(|| AtomicU64::new(INACTIVE)),
+                sealed: Mutex::new(Vec::new()),
             }
         }
-        
+
         /// Pin the current thread - O(1) operation
         pub fn pin(&self, participant_id: usize) -> u64 {
             let epoch = self.global_epoch.load(Ordering::SeqCst);
-            self.participants[participant_id].store(epoch, Ordering::SeqCst);
+            self.slots.store(participant_id, epoch, Ordering::SeqCst);
             epoch
         }
-        
+
         /// Unpin the current thread - O(1) operation
         pub fn unpin(&self, participant_id: usize) {
-            self.participants[participant_id].store(INACTIVE, Ordering::SeqCst);
+            self.slots.store(participant_id, INACTIVE, Ordering::SeqCst);
         }
-        
+
+        /// Lower bound on every active participant's last-observed epoch —
+        /// the same O(T) scan `try_advance` pays for, just returning the
+        /// value instead of a stalled/not-stalled bool.
+        fn scan_min(&self) -> u64 {
+            (0..self.slots.capacity())
+                .map(|i| self.slots.load(i, Ordering::SeqCst))
+                .filter(|&e| e != INACTIVE)
+                .min()
+                .unwrap_or(INACTIVE)
+        }
+
         /// Try to advance the global epoch - O(T) operation
         /// This is where Crossbeam's flat approach has higher overhead
         pub fn try_advance(&self) -> bool {
             let current = self.global_epoch.load(Ordering::SeqCst);
-            let num_parts = self.num_participants.load(Ordering::Relaxed) as usize;
-            
-            // Must scan ALL participants - O(T) complexity
-            for i in 0..num_parts {
-                let p_epoch = self.participants[i].load(Ordering::SeqCst);
-                if p_epoch != INACTIVE && p_epoch < current {
-                    return false;
-                }
+
+            // Must scan every allocated slot - O(T) complexity
+            if self.scan_min() < current {
+                return false;
             }
-            
+
             // All participants caught up, advance
             self.global_epoch
                 .compare_exchange(current, current + 1, Ordering::SeqCst, Ordering::SeqCst)
                 .is_ok()
         }
-        
-        /// Register a new participant
+
+        /// Register a new participant, distributing the id allocation
+        /// across shards rather than serializing on one counter.
         pub fn register(&self) -> usize {
-            let id = self.num_participants.fetch_add(1, Ordering::Relaxed) as usize;
-            assert!(id < MAX_PARTICIPANTS);
-            id
+            self.slots.acquire()
+        }
+
+        /// Returns `participant_id`'s slot to the free list for reuse.
+        pub fn release(&self, participant_id: usize) {
+            self.slots.release(participant_id);
+        }
+
+        /// Bytes currently resident for this collector's epoch-slot
+        /// storage — grows in `SEGMENT_SIZE`-slot steps as participants
+        /// register.
+        pub fn memory_overhead_bytes(&self) -> usize {
+            self.slots.capacity() * std::mem::size_of::<AtomicU64>()
+        }
+
+        /// The global epoch this collector currently holds.
+        pub fn current_epoch(&self) -> u64 {
+            self.global_epoch.load(Ordering::SeqCst)
+        }
+
+        /// Hands a filled garbage block off to this collector's
+        /// reclamation queue.
+        pub fn seal(&self, bag: reclaim::SealedBag) {
+            self.sealed.lock().expect("sealed bag queue poisoned").push(bag);
+        }
+
+        /// Runs [`reclaim::collect_reclaimable`] against this collector's
+        /// own sealed-bag queue and current global minimum.
+        pub fn collect_reclaimable(&self) -> usize {
+            reclaim::collect_reclaimable(&self.sealed, self.scan_min())
         }
     }
-    
+
     impl Default for FlatEpochCollector {
         fn default() -> Self {
             Self::new()
@@ -129,196 +806,1066 @@ mod crossbeam_baseline {
 
 /// Simulated Nexus-style hierarchical epoch for comparison
 mod nexus_baseline {
+    use super::sharded_registry::{EpochSlot, GrowableLevels, SlotStore};
     use super::*;
-    
+
     const BRANCHING_FACTOR: usize = 4;
-    const MAX_PARTICIPANTS: usize = 256;
     const INACTIVE: u64 = u64::MAX;
-    
-    /// Hierarchical epoch collector (Nexus-style)
-    pub struct HierarchicalEpochCollector {
+
+    /// Cache-line-padded newtype around an `AtomicU64`. 128 bytes (rather
+    /// than the typical 64-byte line) also covers adjacent-line hardware
+    /// prefetchers, so a store to one slot never invalidates a neighboring
+    /// thread's slot.
+    #[repr(align(128))]
+    pub struct PaddedAtomicU64(AtomicU64);
+
+    impl EpochSlot for PaddedAtomicU64 {
+        fn new(value: u64) -> Self {
+            Self(AtomicU64::new(value))
+        }
+        fn load(&self, order: Ordering) -> u64 {
+            self.0.load(order)
+        }
+        fn store(&self, value: u64, order: Ordering) {
+            self.0.store(value, order)
+        }
+    }
+
+    /// Shared implementation behind both [`HierarchicalEpochCollector`] and
+    /// [`HierarchicalEpochCollectorUnpadded`] — identical propagation and
+    /// advancement logic, generic over the padded/unpacked slot
+    /// representation `T` so the A/B pair isn't two hand-copied structs.
+    /// Leaf slots live in a [`SlotStore`] (growable, sharded registration);
+    /// the aggregation tiers live in a [`GrowableLevels`] whose depth is
+    /// derived as `ceil(log_BRANCHING_FACTOR(leaf_capacity))` rather than
+    /// fixed at four.
+    struct HierarchicalImpl<T: EpochSlot> {
         global_epoch: AtomicU64,
-        /// Level 0: Thread-local epochs
-        local_epochs: Box<[AtomicU64; MAX_PARTICIPANTS]>,
-        /// Level 1: Aggregated minimums (64 nodes)
-        level1: Box<[AtomicU64; 64]>,
-        /// Level 2: Aggregated minimums (16 nodes)
-        level2: Box<[AtomicU64; 16]>,
-        /// Level 3: Aggregated minimums (4 nodes)
-        level3: Box<[AtomicU64; 4]>,
-        num_participants: AtomicU64,
-    }
-    
-    impl HierarchicalEpochCollector {
-        pub fn new() -> Self {
+        leaves: SlotStore<T>,
+        levels: GrowableLevels<T>,
+        /// Sealed garbage blocks awaiting reclamation — see the `reclaim`
+        /// module docs.
+        sealed: Mutex<Vec<reclaim::SealedBag>>,
+    }
+
+    impl<T: EpochSlot> HierarchicalImpl<T> {
+        fn new(make_slot: fn() -> T) -> Self {
             Self {
                 global_epoch: AtomicU64::new(0),
-                local_epochs: Box::new([(); MAX_PARTICIPANTS].map(|_| AtomicU64::new(INACTIVE))),
-                level1: Box::new([(); 64].map(|_| AtomicU64::new(INACTIVE))),
-                level2: Box::new([(); 16].map(|_| AtomicU64::new(INACTIVE))),
-                level3: Box::new([(); 4].map(|_| AtomicU64::new(INACTIVE))),
-                num_participants: AtomicU64::new(0),
+                leaves: SlotStore::new(make_slot),
+                levels: GrowableLevels::new(BRANCHING_FACTOR),
+                sealed: Mutex::new(Vec::new()),
             }
         }
-        
+
         /// Pin with lazy hierarchical propagation - O(1) with amortized O(log T)
-        pub fn pin(&self, participant_id: usize) -> u64 {
+        fn pin(&self, participant_id: usize) -> u64 {
             let epoch = self.global_epoch.load(Ordering::SeqCst);
-            self.local_epochs[participant_id].store(epoch, Ordering::SeqCst);
+            self.leaves.store(participant_id, epoch, Ordering::SeqCst);
             self.propagate_up(participant_id);
             epoch
         }
-        
-        pub fn unpin(&self, participant_id: usize) {
-            self.local_epochs[participant_id].store(INACTIVE, Ordering::SeqCst);
+
+        fn unpin(&self, participant_id: usize) {
+            self.leaves.store(participant_id, INACTIVE, Ordering::SeqCst);
             self.propagate_up(participant_id);
         }
-        
+
         fn propagate_up(&self, participant_id: usize) {
-            let l1_idx = participant_id / BRANCHING_FACTOR;
-            let l1_start = l1_idx * BRANCHING_FACTOR;
-            let l1_min = (l1_start..l1_start + BRANCHING_FACTOR)
-                .filter(|&i| i < MAX_PARTICIPANTS)
-                .map(|i| self.local_epochs[i].load(Ordering::Relaxed))
-                .filter(|&e| e != INACTIVE)
-                .min()
-                .unwrap_or(INACTIVE);
-            self.level1[l1_idx].store(l1_min, Ordering::Release);
-            
-            let l2_idx = l1_idx / BRANCHING_FACTOR;
-            let l2_start = l2_idx * BRANCHING_FACTOR;
-            let l2_min = (l2_start..l2_start + BRANCHING_FACTOR)
-                .filter(|&i| i < 64)
-                .map(|i| self.level1[i].load(Ordering::Relaxed))
-                .filter(|&e| e != INACTIVE)
-                .min()
-                .unwrap_or(INACTIVE);
-            self.level2[l2_idx].store(l2_min, Ordering::Release);
-            
-            let l3_idx = l2_idx / BRANCHING_FACTOR;
-            let l3_start = l3_idx * BRANCHING_FACTOR;
-            let l3_min = (l3_start..l3_start + BRANCHING_FACTOR)
-                .filter(|&i| i < 16)
-                .map(|i| self.level2[i].load(Ordering::Relaxed))
+            let leaf_capacity = self.leaves.capacity();
+            let levels = self.levels.read();
+            let Some(level1) = levels.tiers.first() else { return };
+
+            let parent = participant_id / BRANCHING_FACTOR;
+            let group_start = parent * BRANCHING_FACTOR;
+            let mut min = (group_start..group_start + BRANCHING_FACTOR)
+                .filter(|&i| i < leaf_capacity)
+                .map(|i| self.leaves.load(i, Ordering::Relaxed))
                 .filter(|&e| e != INACTIVE)
                 .min()
                 .unwrap_or(INACTIVE);
-            self.level3[l3_idx].store(l3_min, Ordering::Release);
+            level1[parent].store(min, Ordering::Release);
+
+            let mut idx = parent;
+            for level in 1..levels.tiers.len() {
+                let parent_idx = idx / BRANCHING_FACTOR;
+                let group_start = parent_idx * BRANCHING_FACTOR;
+                min = (group_start..group_start + BRANCHING_FACTOR)
+                    .filter(|&i| i < levels.tiers[level - 1].len())
+                    .map(|i| levels.tiers[level - 1][i].load(Ordering::Relaxed))
+                    .filter(|&e| e != INACTIVE)
+                    .min()
+                    .unwrap_or(INACTIVE);
+                levels.tiers[level][parent_idx].store(min, Ordering::Release);
+                idx = parent_idx;
+            }
         }
-        
-        /// Try to advance - O(log T) by only checking top level
-        pub fn try_advance(&self) -> bool {
+
+        /// Lower bound on every active participant's last-observed epoch,
+        /// read from the root tier alone — O(log T) rather than the O(T)
+        /// scan `crossbeam_baseline::FlatEpochCollector` needs.
+        fn global_min(&self) -> u64 {
+            let levels = self.levels.read();
+            levels
+                .tiers
+                .last()
+                .map(|root| {
+                    root.iter()
+                        .map(|slot| slot.load(Ordering::Acquire))
+                        .filter(|&e| e != INACTIVE)
+                        .min()
+                        .unwrap_or(INACTIVE)
+                })
+                .unwrap_or(INACTIVE)
+        }
+
+        /// Try to advance - O(log T) by only checking the root tier
+        fn try_advance(&self) -> bool {
             let current = self.global_epoch.load(Ordering::SeqCst);
-            
-            // Only check top level - O(4) = O(1)
-            let global_min = self.level3.iter()
-                .map(|a| a.load(Ordering::Acquire))
-                .filter(|&e| e != INACTIVE)
-                .min()
-                .unwrap_or(INACTIVE);
-            
+            let global_min = self.global_min();
+
             if global_min != INACTIVE && global_min < current {
                 return false;
             }
-            
+
             self.global_epoch
                 .compare_exchange(current, current + 1, Ordering::SeqCst, Ordering::SeqCst)
                 .is_ok()
         }
-        
-        pub fn register(&self) -> usize {
-            let id = self.num_participants.fetch_add(1, Ordering::Relaxed) as usize;
-            assert!(id < MAX_PARTICIPANTS);
+
+        fn register(&self) -> usize {
+            let id = self.leaves.acquire();
+            self.levels.ensure_capacity(self.leaves.capacity());
             id
         }
+
+        fn release(&self, participant_id: usize) {
+            self.leaves.release(participant_id);
+        }
+
+        fn memory_overhead_bytes(&self) -> usize {
+            let leaves = self.leaves.capacity() * std::mem::size_of::<T>();
+            let tiers: usize = self
+                .levels
+                .read()
+                .tiers
+                .iter()
+                .map(|tier| tier.len() * std::mem::size_of::<T>())
+                .sum();
+            leaves + tiers
+        }
+
+        /// The global epoch this collector currently holds.
+        fn current_epoch(&self) -> u64 {
+            self.global_epoch.load(Ordering::SeqCst)
+        }
+
+        /// Hands a filled garbage block off to this collector's
+        /// reclamation queue.
+        fn seal(&self, bag: reclaim::SealedBag) {
+            self.sealed.lock().expect("sealed bag queue poisoned").push(bag);
+        }
+
+        /// Runs [`reclaim::collect_reclaimable`] against this collector's
+        /// own sealed-bag queue and current `global_min()`.
+        fn collect_reclaimable(&self) -> usize {
+            reclaim::collect_reclaimable(&self.sealed, self.global_min())
+        }
     }
-    
+
+    /// Hierarchical epoch collector (Nexus-style).
+    ///
+    /// Only the leaf level and level1 are cache-padded: those are the
+    /// levels every `pin`/`unpin` writes to, so false sharing there is the
+    /// contention source worth paying 128 bytes/slot for. Higher levels
+    /// aggregate over 4x as many descendants each and are written far less
+    /// often, so in the padded build both still share the same `T` as the
+    /// leaves for simplicity — see [`HierarchicalEpochCollectorUnpadded`]
+    /// for the fully-packed A/B twin.
+    pub struct HierarchicalEpochCollector(HierarchicalImpl<PaddedAtomicU64>);
+
+    impl HierarchicalEpochCollector {
+        pub fn new() -> Self {
+            Self(HierarchicalImpl::new(|| PaddedAtomicU64::new(INACTIVE)))
+        }
+
+        pub fn pin(&self, participant_id: usize) -> u64 {
+            self.0.pin(participant_id)
+        }
+
+        pub fn unpin(&self, participant_id: usize) {
+            self.0.unpin(participant_id)
+        }
+
+        pub fn try_advance(&self) -> bool {
+            self.0.try_advance()
+        }
+
+        pub fn register(&self) -> usize {
+            self.0.register()
+        }
+
+        pub fn release(&self, participant_id: usize) {
+            self.0.release(participant_id)
+        }
+
+        pub fn memory_overhead_bytes(&self) -> usize {
+            self.0.memory_overhead_bytes()
+        }
+
+        pub fn current_epoch(&self) -> u64 {
+            self.0.current_epoch()
+        }
+
+        pub fn seal(&self, bag: reclaim::SealedBag) {
+            self.0.seal(bag)
+        }
+
+        pub fn collect_reclaimable(&self) -> usize {
+            self.0.collect_reclaimable()
+        }
+    }
+
     impl Default for HierarchicalEpochCollector {
         fn default() -> Self {
             Self::new()
         }
     }
+
+    /// Unpadded twin of [`HierarchicalEpochCollector`] — identical
+    /// propagation logic via the shared [`HierarchicalImpl`], but every
+    /// level packed contiguously with no padding. Exists purely so
+    /// `run_benchmarks` can A/B the padded and unpadded variants under the
+    /// same workload and attribute the resulting p99/p999 delta to
+    /// false-sharing removal alone.
+    pub struct HierarchicalEpochCollectorUnpadded(HierarchicalImpl<AtomicU64>);
+
+    impl HierarchicalEpochCollectorUnpadded {
+        pub fn new() -> Self {
+            Self(HierarchicalImpl::new(|| AtomicU64::new(INACTIVE)))
+        }
+
+        pub fn pin(&self, participant_id: usize) -> u64 {
+            self.0.pin(participant_id)
+        }
+
+        pub fn unpin(&self, participant_id: usize) {
+            self.0.unpin(participant_id)
+        }
+
+        pub fn try_advance(&self) -> bool {
+            self.0.try_advance()
+        }
+
+        pub fn register(&self) -> usize {
+            self.0.register()
+        }
+
+        pub fn release(&self, participant_id: usize) {
+            self.0.release(participant_id)
+        }
+
+        pub fn memory_overhead_bytes(&self) -> usize {
+            self.0.memory_overhead_bytes()
+        }
+
+        pub fn current_epoch(&self) -> u64 {
+            self.0.current_epoch()
+        }
+
+        pub fn seal(&self, bag: reclaim::SealedBag) {
+            self.0.seal(bag)
+        }
+
+        pub fn collect_reclaimable(&self) -> usize {
+            self.0.collect_reclaimable()
+        }
+    }
+
+    impl Default for HierarchicalEpochCollectorUnpadded {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+}
+
+/// Folds a [`Histogram`] into a [`BenchmarkResult`]. Shared by every row
+/// below so the padded/unpadded/crossbeam variants are summarized
+/// identically and are safe to compare directly. No sorting, and no
+/// allocation beyond the histogram's own fixed bucket array.
+fn summarize(
+    name: &str,
+    thread_count: usize,
+    latencies: Histogram,
+    memory_overhead_bytes: usize,
+) -> BenchmarkResult {
+    let mean = latencies.mean();
+
+    BenchmarkResult {
+        name: name.to_string(),
+        thread_count,
+        mean_latency_ns: mean,
+        p50_latency_ns: latencies.quantile(0.50),
+        p99_latency_ns: latencies.quantile(0.99),
+        p999_latency_ns: latencies.quantile(0.999),
+        p9999_latency_ns: latencies.quantile(0.9999),
+        max_latency_ns: latencies.max(),
+        throughput_ops_per_sec: 1e9 / mean,
+        memory_overhead_bytes,
+    }
+}
+
+/// Common surface both the flat and hierarchical epoch collectors expose,
+/// so [`run_contention_workload`] can drive any of them without a separate
+/// copy of the thread-spawning harness per collector type.
+trait EpochCollector {
+    fn pin(&self, participant_id: usize) -> u64;
+    fn unpin(&self, participant_id: usize);
+    fn register(&self) -> usize;
+    fn release(&self, participant_id: usize);
+    fn try_advance(&self) -> bool;
+    fn memory_overhead_bytes(&self) -> usize;
+
+    /// The global epoch this collector currently holds, independent of any
+    /// one participant's pinned view of it.
+    fn current_epoch(&self) -> u64;
+
+    /// Hands a filled garbage block off to this collector's reclamation
+    /// queue, stamped with the epoch it was sealed at.
+    fn seal(&self, bag: reclaim::SealedBag);
+
+    /// Runs [`reclaim::collect_reclaimable`] against this collector's own
+    /// sealed-bag queue and its current global minimum.
+    fn collect_reclaimable(&self) -> usize;
+
+    /// `try_advance`, then `collect_reclaimable` if it succeeded.
+    fn try_advance_and_collect(&self) -> usize {
+        if self.try_advance() {
+            self.collect_reclaimable()
+        } else {
+            0
+        }
+    }
+}
+
+impl EpochCollector for crossbeam_baseline::FlatEpochCollector {
+    fn pin(&self, participant_id: usize) -> u64 {
+        crossbeam_baseline::FlatEpochCollector::pin(self, participant_id)
+    }
+    fn unpin(&self, participant_id: usize) {
+        crossbeam_baseline::FlatEpochCollector::unpin(self, participant_id)
+    }
+    fn register(&self) -> usize {
+        crossbeam_baseline::FlatEpochCollector::register(self)
+    }
+    fn release(&self, participant_id: usize) {
+        crossbeam_baseline::FlatEpochCollector::release(self, participant_id)
+    }
+    fn try_advance(&self) -> bool {
+        crossbeam_baseline::FlatEpochCollector::try_advance(self)
+    }
+    fn memory_overhead_bytes(&self) -> usize {
+        crossbeam_baseline::FlatEpochCollector::memory_overhead_bytes(self)
+    }
+    fn current_epoch(&self) -> u64 {
+        crossbeam_baseline::FlatEpochCollector::current_epoch(self)
+    }
+    fn seal(&self, bag: reclaim::SealedBag) {
+        crossbeam_baseline::FlatEpochCollector::seal(self, bag)
+    }
+    fn collect_reclaimable(&self) -> usize {
+        crossbeam_baseline::FlatEpochCollector::collect_reclaimable(self)
+    }
+}
+
+impl EpochCollector for nexus_baseline::HierarchicalEpochCollector {
+    fn pin(&self, participant_id: usize) -> u64 {
+        nexus_baseline::HierarchicalEpochCollector::pin(self, participant_id)
+    }
+    fn unpin(&self, participant_id: usize) {
+        nexus_baseline::HierarchicalEpochCollector::unpin(self, participant_id)
+    }
+    fn register(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollector::register(self)
+    }
+    fn release(&self, participant_id: usize) {
+        nexus_baseline::HierarchicalEpochCollector::release(self, participant_id)
+    }
+    fn try_advance(&self) -> bool {
+        nexus_baseline::HierarchicalEpochCollector::try_advance(self)
+    }
+    fn memory_overhead_bytes(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollector::memory_overhead_bytes(self)
+    }
+    fn current_epoch(&self) -> u64 {
+        nexus_baseline::HierarchicalEpochCollector::current_epoch(self)
+    }
+    fn seal(&self, bag: reclaim::SealedBag) {
+        nexus_baseline::HierarchicalEpochCollector::seal(self, bag)
+    }
+    fn collect_reclaimable(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollector::collect_reclaimable(self)
+    }
+}
+
+impl EpochCollector for nexus_baseline::HierarchicalEpochCollectorUnpadded {
+    fn pin(&self, participant_id: usize) -> u64 {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::pin(self, participant_id)
+    }
+    fn unpin(&self, participant_id: usize) {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::unpin(self, participant_id)
+    }
+    fn register(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::register(self)
+    }
+    fn release(&self, participant_id: usize) {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::release(self, participant_id)
+    }
+    fn try_advance(&self) -> bool {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::try_advance(self)
+    }
+    fn memory_overhead_bytes(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::memory_overhead_bytes(self)
+    }
+    fn current_epoch(&self) -> u64 {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::current_epoch(self)
+    }
+    fn seal(&self, bag: reclaim::SealedBag) {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::seal(self, bag)
+    }
+    fn collect_reclaimable(&self) -> usize {
+        nexus_baseline::HierarchicalEpochCollectorUnpadded::collect_reclaimable(self)
+    }
+}
+
+/// Background collector thread that replaces busy-spinning `try_advance`
+/// with exponential backoff, and the harness that drives it under the same
+/// contention workload as [`run_contention_workload`].
+mod collector_daemon {
+    use super::*;
+
+    /// Consecutive `try_advance` attempts tried at full speed (with just a
+    /// `spin_loop` hint between them) before escalating to yielding.
+    const SPIN_ATTEMPTS: u32 = 50;
+    /// Consecutive `thread::yield_now` + `try_advance` attempts tried before
+    /// escalating to parking.
+    const YIELD_ATTEMPTS: u32 = 20;
+    /// Initial park timeout; doubles on every consecutive failed round up to
+    /// `MAX_PARK`, and resets the moment an attempt succeeds.
+    const INITIAL_PARK: Duration = Duration::from_micros(50);
+    const MAX_PARK: Duration = Duration::from_millis(5);
+
+    /// Runs one `try_advance` (and, if it succeeds, `collect_reclaimable`)
+    /// against `collector`, timing the call. Returns `(latency_ns, advanced)`.
+    fn timed_attempt<C: EpochCollector>(collector: &C) -> (f64, bool) {
+        let start = Instant::now();
+        let advanced = collector.try_advance();
+        if advanced {
+            collector.collect_reclaimable();
+        }
+        (start.elapsed().as_nanos() as f64, advanced)
+    }
+
+    /// Spin/yield/park backoff loop, run on the daemon's own thread until
+    /// `stop` is set. Every attempted `try_advance` call's latency is
+    /// recorded, whether or not it actually advanced the epoch, so the
+    /// resulting samples are directly comparable to the pure spin loop's
+    /// `{prefix}_advance` row.
+    fn backoff_loop<C: EpochCollector>(collector: &C, stop: &AtomicBool) -> Histogram {
+        let mut latencies = Histogram::new();
+        let mut park_timeout = INITIAL_PARK;
+
+        while !stop.load(Ordering::Relaxed) {
+            let mut advanced = false;
+
+            for _ in 0..SPIN_ATTEMPTS {
+                if stop.load(Ordering::Relaxed) {
+                    return latencies;
+                }
+                let (latency, did_advance) = timed_attempt(collector);
+                latencies.record(latency);
+                if did_advance {
+                    advanced = true;
+                    break;
+                }
+                std::hint::spin_loop();
+            }
+
+            if !advanced {
+                for _ in 0..YIELD_ATTEMPTS {
+                    if stop.load(Ordering::Relaxed) {
+                        return latencies;
+                    }
+                    thread::yield_now();
+                    let (latency, did_advance) = timed_attempt(collector);
+                    latencies.record(latency);
+                    if did_advance {
+                        advanced = true;
+                        break;
+                    }
+                }
+            }
+
+            if advanced {
+                park_timeout = INITIAL_PARK;
+                continue;
+            }
+
+            thread::park_timeout(park_timeout);
+            park_timeout = (park_timeout * 2).min(MAX_PARK);
+        }
+
+        latencies
+    }
+
+    /// A spawned background collector. [`join`](Self::join) signals it to
+    /// stop, wakes it (in case it's currently parked), and returns every
+    /// `try_advance` latency it recorded.
+    pub(crate) struct CollectorDaemon {
+        stop: Arc<AtomicBool>,
+        thread: thread::Thread,
+        handle: thread::JoinHandle<Histogram>,
+    }
+
+    impl CollectorDaemon {
+        /// Spawns the backoff loop against `collector` on its own thread.
+        pub(crate) fn spawn<C: EpochCollector + Send + Sync + 'static>(collector: Arc<C>) -> Self {
+            let stop = Arc::new(AtomicBool::new(false));
+            let stop_for_daemon = Arc::clone(&stop);
+
+            let handle = thread::Builder::new()
+                .name("collector-daemon".to_string())
+                .spawn(move || backoff_loop(&*collector, &stop_for_daemon))
+                .expect("failed to spawn collector daemon thread");
+
+            let thread = handle.thread().clone();
+
+            Self { stop, thread, handle }
+        }
+
+        /// A cheap, cloneable handle pinning threads can unpark directly
+        /// without going through the daemon itself.
+        pub(crate) fn thread(&self) -> thread::Thread {
+            self.thread.clone()
+        }
+
+        /// Stops the daemon, unparking it in case it's currently parked so
+        /// it notices promptly, and returns its recorded latencies.
+        pub(crate) fn join(self) -> Histogram {
+            self.stop.store(true, Ordering::Release);
+            self.thread.unpark();
+            self.handle.join().expect("collector daemon thread panicked")
+        }
+    }
+
+    /// Like [`run_contention_workload`], but the coordinator is a
+    /// [`CollectorDaemon`] backing off instead of busy-spinning: workers
+    /// unpark it directly the moment their own `try_advance` call (right
+    /// after unpinning) tells them they were the last laggard holding the
+    /// epoch back. Returns the daemon's `try_advance` latencies.
+    pub(crate) fn run_daemon_contention_workload<C: EpochCollector + Send + Sync + 'static>(
+        collector: Arc<C>,
+        thread_count: usize,
+        ops_per_thread: usize,
+    ) -> Histogram {
+        let daemon = CollectorDaemon::spawn(Arc::clone(&collector));
+
+        let start_gate = Arc::new(AtomicBool::new(false));
+        let barrier = Arc::new(Barrier::new(thread_count + 1));
+
+        let handles: Vec<_> = (0..thread_count)
+            .map(|_| {
+                let collector = Arc::clone(&collector);
+                let start_gate = Arc::clone(&start_gate);
+                let barrier = Arc::clone(&barrier);
+                let daemon_thread = daemon.thread();
+                thread::spawn(move || {
+                    let participant = register_participant(collector);
+
+                    barrier.wait();
+                    while !start_gate.load(Ordering::Acquire) {
+                        std::hint::spin_loop();
+                    }
+
+                    for _ in 0..ops_per_thread {
+                        participant.collector.pin(participant.id());
+                        std::hint::black_box(participant.id());
+                        participant.collector.unpin(participant.id());
+
+                        // Unpinning may have just made this thread the last
+                        // laggard the daemon was waiting on; if so, wake it
+                        // rather than let it sleep out its current backoff.
+                        if participant.collector.try_advance() {
+                            daemon_thread.unpark();
+                        }
+                    }
+                })
+            })
+            .collect();
+
+        barrier.wait();
+        start_gate.store(true, Ordering::Release);
+
+        for handle in handles {
+            handle.join().expect("worker thread panicked");
+        }
+
+        daemon.join()
+    }
+}
+
+/// RAII participant handle returned by [`register_participant`]: on drop it
+/// unpins (in case the caller forgot to) and returns its slot to the
+/// collector's free list, so a long-running server churning worker threads
+/// never leaks registry slots the way a bare `register()` id would if the
+/// caller dropped it without ever calling `release`.
+struct Participant<C: EpochCollector> {
+    collector: Arc<C>,
+    id: usize,
+    /// This participant's in-progress (not yet sealed) garbage block —
+    /// only ever touched by the thread that owns this `Participant`.
+    garbage: UnsafeCell<reclaim::Block>,
+}
+
+impl<C: EpochCollector> Participant<C> {
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    /// Retires `ptr` for destruction via `dtor`: appends it to this
+    /// participant's in-progress garbage block, sealing and handing the
+    /// block off to the collector's reclamation queue once it fills. See
+    /// the `reclaim` module docs for the block cache this batches through.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be valid and not destroyed by any other means before the
+    /// collector's `global_min()` has passed the epoch this call seals it
+    /// at.
+    unsafe fn retire<T: 'static>(&self, ptr: *mut T, dtor: unsafe fn(*mut T)) {
+        let entry = unsafe { reclaim::Retired::new(ptr, dtor) };
+        // SAFETY: only this participant's owning thread ever touches its
+        // own garbage block, mirroring nexus-memory's per-participant
+        // `local_garbage` bag.
+        let block = unsafe { &mut *self.garbage.get() };
+        block.push(entry);
+
+        if block.is_full() {
+            let epoch = self.collector.current_epoch();
+            let full = mem::replace(block, reclaim::take_block());
+            self.collector.seal(reclaim::SealedBag { epoch, block: full });
+        }
+    }
+}
+
+impl<C: EpochCollector> Drop for Participant<C> {
+    fn drop(&mut self) {
+        self.collector.unpin(self.id);
+        self.collector.release(self.id);
+
+        // Flush whatever is still sitting in this participant's
+        // in-progress block instead of silently dropping its destructors
+        // along with the participant.
+        let block = mem::replace(self.garbage.get_mut(), reclaim::take_block());
+        if block.len() > 0 {
+            let epoch = self.collector.current_epoch();
+            self.collector.seal(reclaim::SealedBag { epoch, block });
+        } else {
+            reclaim::recycle_block(block);
+        }
+    }
+}
+
+/// Registers a new participant against `collector`, returning an RAII
+/// guard rather than a bare id.
+fn register_participant<C: EpochCollector>(collector: Arc<C>) -> Participant<C> {
+    let id = collector.register();
+    Participant { collector, id, garbage: UnsafeCell::new(reclaim::take_block()) }
+}
+
+/// Per-operation latency histograms merged from a [`run_contention_workload`]
+/// run: `pin`/`unpin` histograms are merged from the worker threads,
+/// `advance` comes from the coordinator (the calling thread).
+struct ContentionSamples {
+    pin_latencies: Histogram,
+    unpin_latencies: Histogram,
+    advance_latencies: Histogram,
+}
+
+/// Drives `collector` with `thread_count` worker threads, each registering
+/// once (via [`register_participant`], so its slot is returned to the free
+/// list when the worker exits) and then looping pin -> (short critical
+/// section) -> unpin for `ops_per_thread` iterations, timing every pin and
+/// every unpin. A shared `Barrier` brings every worker (and the
+/// coordinator) to the starting line, then an `AtomicBool` start gate
+/// releases them simultaneously — mirroring a send-latency style bench
+/// where producers (the pinners) and a consumer (the coordinator's
+/// `try_advance` loop) race. The coordinator keeps calling `try_advance`
+/// until the workers finish, timing each call.
+fn run_contention_workload<C: EpochCollector + Send + Sync + 'static>(
+    collector: Arc<C>,
+    thread_count: usize,
+    ops_per_thread: usize,
+) -> ContentionSamples {
+    let start_gate = Arc::new(AtomicBool::new(false));
+    let barrier = Arc::new(Barrier::new(thread_count + 1));
+
+    let handles: Vec<_> = (0..thread_count)
+        .map(|_| {
+            let collector = Arc::clone(&collector);
+            let start_gate = Arc::clone(&start_gate);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                let participant = register_participant(collector);
+                let mut pin_latencies = Histogram::new();
+                let mut unpin_latencies = Histogram::new();
+
+                barrier.wait();
+                while !start_gate.load(Ordering::Acquire) {
+                    std::hint::spin_loop();
+                }
+
+                for _ in 0..ops_per_thread {
+                    let start = Instant::now();
+                    participant.collector.pin(participant.id());
+                    pin_latencies.record(start.elapsed().as_nanos() as f64);
+
+                    // Short critical section under the pin.
+                    std::hint::black_box(participant.id());
+
+                    let start = Instant::now();
+                    participant.collector.unpin(participant.id());
+                    unpin_latencies.record(start.elapsed().as_nanos() as f64);
+                }
+
+                // `participant` drops here, unpinning (a no-op, already
+                // unpinned above) and releasing its slot to the free list.
+                (pin_latencies, unpin_latencies)
+            })
+        })
+        .collect();
+
+    barrier.wait();
+    start_gate.store(true, Ordering::Release);
+
+    let advance_iterations = ops_per_thread * thread_count.max(1);
+    let mut advance_latencies = Histogram::new();
+    for _ in 0..advance_iterations {
+        let start = Instant::now();
+        let _ = collector.try_advance_and_collect();
+        advance_latencies.record(start.elapsed().as_nanos() as f64);
+    }
+
+    let mut pin_latencies = Histogram::new();
+    let mut unpin_latencies = Histogram::new();
+    for handle in handles {
+        let (p, u) = handle.join().expect("worker thread panicked");
+        pin_latencies.merge(&p);
+        unpin_latencies.merge(&u);
+    }
+
+    ContentionSamples {
+        pin_latencies,
+        unpin_latencies,
+        advance_latencies,
+    }
+}
+
+/// Runs `collector` through [`run_contention_workload`] and pushes its
+/// `pin`/`unpin`/`advance` rows (named `{prefix}_pin` etc.) onto `results`.
+/// Memory overhead is read back from `collector` after the workload runs,
+/// since participant storage now grows on demand instead of being a fixed
+/// constant known up front.
+fn bench_collector<C: EpochCollector + Send + Sync + 'static>(
+    prefix: &str,
+    collector: C,
+    thread_count: usize,
+    ops_per_thread: usize,
+    results: &mut Vec<BenchmarkResult>,
+) {
+    let collector = Arc::new(collector);
+    let samples = run_contention_workload(Arc::clone(&collector), thread_count, ops_per_thread);
+    let memory_overhead_bytes = collector.memory_overhead_bytes();
+
+    let pin = summarize(&format!("{prefix}_pin"), thread_count, samples.pin_latencies, memory_overhead_bytes);
+    let unpin = summarize(&format!("{prefix}_unpin"), thread_count, samples.unpin_latencies, memory_overhead_bytes);
+    let advance = summarize(&format!("{prefix}_advance"), thread_count, samples.advance_latencies, memory_overhead_bytes);
+
+    println!(
+        "  {prefix}: pin {:.2} ns, unpin {:.2} ns, advance {:.2} ns",
+        pin.mean_latency_ns, unpin.mean_latency_ns, advance.mean_latency_ns
+    );
+
+    results.push(pin);
+    results.push(unpin);
+    results.push(advance);
+}
+
+/// Runs `collector` through [`collector_daemon::run_daemon_contention_workload`]
+/// and pushes its `{prefix}_daemon_advance` row onto `results` — the same
+/// shape as the `{prefix}_advance` row `bench_collector` records, but driven
+/// by a backing-off daemon thread instead of a pure busy spin, so the two
+/// are directly comparable.
+fn bench_daemon_collector<C: EpochCollector + Send + Sync + 'static>(
+    prefix: &str,
+    collector: C,
+    thread_count: usize,
+    ops_per_thread: usize,
+    results: &mut Vec<BenchmarkResult>,
+) {
+    let collector = Arc::new(collector);
+    let daemon_advance_latencies = collector_daemon::run_daemon_contention_workload(
+        Arc::clone(&collector),
+        thread_count,
+        ops_per_thread,
+    );
+    let memory_overhead_bytes = collector.memory_overhead_bytes();
+
+    let daemon_advance = summarize(
+        &format!("{prefix}_daemon_advance"),
+        thread_count,
+        daemon_advance_latencies,
+        memory_overhead_bytes,
+    );
+
+    println!(
+        "  {prefix}_daemon: advance {:.2} ns (backoff-parked, wake-to-advance)",
+        daemon_advance.mean_latency_ns
+    );
+
+    results.push(daemon_advance);
 }
 
 /// Run benchmarks
 pub fn run_benchmarks() -> Vec<BenchmarkResult> {
     let mut results = vec![];
-    
+
     println!("Crossbeam vs Nexus Epoch Benchmarks");
     println!("===================================\n");
-    
-    for &thread_count in THREAD_COUNTS {
-        println!("Thread count: {}", thread_count);
-        
-        // Crossbeam advance benchmark
-        let collector = Arc::new(crossbeam_baseline::FlatEpochCollector::new());
-        for _ in 0..thread_count {
-            collector.register();
-        }
-        
-        let mut latencies = Vec::with_capacity(BENCHMARK_ITERATIONS);
-        for _ in 0..BENCHMARK_ITERATIONS {
-            let start = Instant::now();
-            let _ = collector.try_advance();
-            latencies.push(start.elapsed().as_nanos() as f64);
-        }
-        
-        latencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        let mean = latencies.iter().sum::<f64>() / latencies.len() as f64;
-        
-        println!("  Crossbeam advance: {:.2} ns (O(T) = O({}))", mean, thread_count);
-        results.push(BenchmarkResult {
-            name: "crossbeam_advance".to_string(),
+
+    let sweep_start = Instant::now();
+    let total_configs = THREAD_COUNTS.len();
+
+    for (config_index, &thread_count) in THREAD_COUNTS.iter().enumerate() {
+        let elapsed = sweep_start.elapsed();
+        let eta = if config_index == 0 {
+            None
+        } else {
+            let avg_per_config = elapsed.div_f64(config_index as f64);
+            Some(avg_per_config * (total_configs - config_index) as u32)
+        };
+        match eta {
+            Some(eta) => println!(
+                "[{}/{total_configs}] thread count {thread_count} (elapsed {:.1}s, eta {:.1}s)",
+                config_index + 1,
+                elapsed.as_secs_f64(),
+                eta.as_secs_f64(),
+            ),
+            None => println!(
+                "[{}/{total_configs}] thread count {thread_count} (elapsed {:.1}s)",
+                config_index + 1,
+                elapsed.as_secs_f64(),
+            ),
+        }
+        let ops_per_thread = (BENCHMARK_ITERATIONS / thread_count).max(1);
+
+        bench_collector(
+            "crossbeam",
+            crossbeam_baseline::FlatEpochCollector::new(),
             thread_count,
-            mean_latency_ns: mean,
-            p50_latency_ns: latencies[latencies.len() / 2],
-            p99_latency_ns: latencies[(latencies.len() as f64 * 0.99) as usize],
-            p999_latency_ns: latencies[(latencies.len() as f64 * 0.999) as usize],
-            throughput_ops_per_sec: 1e9 / mean,
-        });
-        
-        // Nexus advance benchmark
-        let collector = Arc::new(nexus_baseline::HierarchicalEpochCollector::new());
-        for _ in 0..thread_count {
-            collector.register();
-        }
-        
-        let mut latencies = Vec::with_capacity(BENCHMARK_ITERATIONS);
-        for _ in 0..BENCHMARK_ITERATIONS {
-            let start = Instant::now();
-            let _ = collector.try_advance();
-            latencies.push(start.elapsed().as_nanos() as f64);
-        }
-        
-        latencies.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        let mean = latencies.iter().sum::<f64>() / latencies.len() as f64;
-        
-        println!("  Nexus advance: {:.2} ns (O(log T) = O({}))", mean, (thread_count as f64).log2().ceil() as usize);
-        results.push(BenchmarkResult {
-            name: "nexus_advance".to_string(),
+            ops_per_thread,
+            &mut results,
+        );
+
+        bench_collector(
+            "nexus",
+            nexus_baseline::HierarchicalEpochCollector::new(),
+            thread_count,
+            ops_per_thread,
+            &mut results,
+        );
+
+        // The chunk4-1 A/B toggle: identical workload, padding off.
+        bench_collector(
+            "nexus_unpadded",
+            nexus_baseline::HierarchicalEpochCollectorUnpadded::new(),
             thread_count,
-            mean_latency_ns: mean,
-            p50_latency_ns: latencies[latencies.len() / 2],
-            p99_latency_ns: latencies[(latencies.len() as f64 * 0.99) as usize],
-            p999_latency_ns: latencies[(latencies.len() as f64 * 0.999) as usize],
-            throughput_ops_per_sec: 1e9 / mean,
-        });
-        
+            ops_per_thread,
+            &mut results,
+        );
+
+        // The chunk4-5 daemon comparison: same collectors, a backoff-parked
+        // collector thread instead of the coordinator busy-spinning above.
+        bench_daemon_collector(
+            "crossbeam",
+            crossbeam_baseline::FlatEpochCollector::new(),
+            thread_count,
+            ops_per_thread,
+            &mut results,
+        );
+
+        bench_daemon_collector(
+            "nexus",
+            nexus_baseline::HierarchicalEpochCollector::new(),
+            thread_count,
+            ops_per_thread,
+            &mut results,
+        );
+
+        bench_daemon_collector(
+            "nexus_unpadded",
+            nexus_baseline::HierarchicalEpochCollectorUnpadded::new(),
+            thread_count,
+            ops_per_thread,
+            &mut results,
+        );
+
         println!();
     }
-    
+
     results
 }
 
+/// Wraps the system allocator with an atomic allocation counter, so
+/// [`allocation_accounting`] can report real allocations-per-
+/// reclaimed-object instead of inferring it indirectly — `reclaim`'s own
+/// `BLOCKS_ALLOCATED` counter only tracks blocks it mints itself, not every
+/// allocation in the process, so this also catches anything that slips
+/// past the cache (or a regression that stops recycling).
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl std::alloc::GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: std::alloc::Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { std::alloc::System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: std::alloc::Layout) {
+        unsafe { std::alloc::System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Retires and reclaims `rounds * BLOCK_CAPACITY` heap-allocated `usize`s
+/// against a fresh `collector` from a single participant, printing
+/// allocations observed (via the [`CountingAllocator`] above) per object
+/// reclaimed each round. The first round pays for minting a fresh garbage
+/// block; once the thread-local free-list has a block to recycle, a
+/// steady-state retire/reclaim cycle should allocate nothing at all for
+/// bag storage, driving the ratio toward zero.
+fn allocation_accounting<C: EpochCollector + Send + Sync + 'static>(
+    name: &str,
+    collector: C,
+    rounds: usize,
+) {
+    let collector = Arc::new(collector);
+    let participant = register_participant(Arc::clone(&collector));
+
+    println!("  {name}:");
+    for round in 0..rounds {
+        let before = ALLOCATIONS.load(Ordering::Relaxed);
+        let blocks_before = reclaim::BLOCKS_ALLOCATED.load(Ordering::Relaxed);
+
+        for _ in 0..reclaim::BLOCK_CAPACITY {
+            let boxed = Box::into_raw(Box::new(0usize));
+            // SAFETY: `boxed` was just allocated above and is retired
+            // exactly once, with a dtor matching its real type.
+            unsafe {
+                participant.retire(boxed, |p: *mut usize| {
+                    drop(Box::from_raw(p));
+                });
+            }
+        }
+        let reclaimed = collector.try_advance_and_collect();
+
+        let allocs = ALLOCATIONS.load(Ordering::Relaxed) - before;
+        let blocks_minted = reclaim::BLOCKS_ALLOCATED.load(Ordering::Relaxed) - blocks_before;
+        println!(
+            "    round {round}: {allocs} allocation(s) ({blocks_minted} new block(s)) for {} retired object(s) ({reclaimed} reclaimed this pass)",
+            reclaim::BLOCK_CAPACITY
+        );
+    }
+}
+
+/// A p99 more than this many percent worse than its baseline counterpart is
+/// reported as a regression. Overridable via `NEXUS_BENCH_REGRESSION_PCT` so
+/// CI can tighten or loosen the gate without a rebuild.
+const REGRESSION_THRESHOLD_PCT: f64 = 10.0;
+
+const BASELINE_CSV_PATH: &str = "crossbeam_comparison.csv";
+
+/// Loads whatever `crossbeam_comparison.csv` the *previous* run left behind,
+/// keyed by `(name, thread_count)` so [`main`] can diff each new row against
+/// its counterpart. Returns an empty map if there is no previous run (or it
+/// fails to parse) rather than erroring — the first run on a machine has no
+/// baseline to compare against, and that's fine.
+fn load_baseline(path: &str) -> HashMap<(String, usize), BenchmarkResult> {
+    let Ok(contents) = std::fs::read_to_string(path) else {
+        return HashMap::new();
+    };
+
+    contents
+        .lines()
+        .skip(1) // header
+        .filter_map(BenchmarkResult::from_csv_row)
+        .map(|r| ((r.name.clone(), r.thread_count), r))
+        .collect()
+}
+
 fn main() {
+    let regression_threshold_pct = std::env::var("NEXUS_BENCH_REGRESSION_PCT")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(REGRESSION_THRESHOLD_PCT);
+
+    let baseline = load_baseline(BASELINE_CSV_PATH);
     let results = run_benchmarks();
-    
+
     // Export CSV
     use std::io::Write;
-    let mut file = std::fs::File::create("crossbeam_comparison.csv").unwrap();
-    writeln!(file, "name,thread_count,mean_ns,p50_ns,p99_ns,p999_ns,throughput").unwrap();
+    let mut file = std::fs::File::create(BASELINE_CSV_PATH).unwrap();
+    writeln!(
+        file,
+        "name,thread_count,mean_ns,p50_ns,p99_ns,p999_ns,p9999_ns,max_ns,throughput,memory_overhead_bytes"
+    )
+    .unwrap();
     for r in &results {
         writeln!(file, "{}", r.to_csv_row()).unwrap();
     }
-    println!("Results exported to crossbeam_comparison.csv");
+    println!("Results exported to {BASELINE_CSV_PATH}");
+
+    println!("\nBlock-Cache Allocation Accounting");
+    println!("=================================\n");
+    allocation_accounting("crossbeam", crossbeam_baseline::FlatEpochCollector::new(), 4);
+    allocation_accounting("nexus", nexus_baseline::HierarchicalEpochCollector::new(), 4);
+
+    let regressions: Vec<(&BenchmarkResult, &BenchmarkResult, f64)> = results
+        .iter()
+        .filter_map(|r| {
+            let base = baseline.get(&(r.name.clone(), r.thread_count))?;
+            if base.p99_latency_ns <= 0.0 {
+                return None;
+            }
+            let pct_change = (r.p99_latency_ns - base.p99_latency_ns) / base.p99_latency_ns * 100.0;
+            (pct_change > regression_threshold_pct).then_some((base, r, pct_change))
+        })
+        .collect();
+
+    if regressions.is_empty() {
+        return;
+    }
+
+    println!("\nRegression Report (p99 > {regression_threshold_pct:.1}% worse than baseline)");
+    println!("=================================================================");
+    for (base, current, pct_change) in &regressions {
+        println!(
+            "  {} @ {} threads: {:.2} ns -> {:.2} ns ({:+.1}%)",
+            current.name, current.thread_count, base.p99_latency_ns, current.p99_latency_ns, pct_change
+        );
+    }
+    std::process::exit(1);
 }
@@ -7,6 +7,12 @@
 //! - Cross-paradigm overhead: ≤ O(log n) for n elements
 //! - Zero-copy transformations: O(1) memory overhead
 //! - Unified execution: 15× faster than traditional approaches
+//!
+//! With the `arrow-backend` feature enabled, `ColumnView` backs
+//! `f64` transitions with a shared Arrow buffer so `batch_to_stream_view`/
+//! `stream_to_batch_view` produce offset/length views rather than the
+//! clones `batch_to_stream`/`stream_to_batch` allocate — see
+//! `bench_zero_copy_columnar` for the O(1)-vs-O(n) comparison.
 
 use criterion::{
     black_box, criterion_group, criterion_main,
@@ -24,6 +30,15 @@ use std::{
 
 use crossbeam_utils::CachePadded;
 use parking_lot::Mutex;
+use rand::SeedableRng;
+use rand_distr::{Distribution, Normal, Uniform};
+use rand_xoshiro::Xoshiro256Plus;
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+#[cfg(feature = "arrow-backend")]
+use arrow::array::{Array, Float64Array};
 
 // ============================================================================
 // Paradigm Abstractions
@@ -39,6 +54,17 @@ impl<T: Clone> BatchDataset<T> {
         Self { data }
     }
 
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn data(&self) -> &[T] {
+        &self.data
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+impl<T: Clone> BatchDataset<T> {
     pub fn map<U, F>(&self, f: F) -> BatchDataset<U>
     where
         F: Fn(&T) -> U,
@@ -63,13 +89,46 @@ impl<T: Clone> BatchDataset<T> {
     {
         self.data.iter().fold(init, f)
     }
+}
 
-    pub fn len(&self) -> usize {
-        self.data.len()
+/// Data-parallel `map`/`filter`/`reduce` over rayon's `par_iter`, dominating
+/// `batch_to_graph`'s O(n²) edge construction at the larger benchmark sizes
+/// where the serial path above bottlenecks.
+#[cfg(feature = "parallel")]
+impl<T: Clone + Send + Sync> BatchDataset<T> {
+    pub fn map<U, F>(&self, f: F) -> BatchDataset<U>
+    where
+        U: Send,
+        F: Fn(&T) -> U + Sync + Send,
+    {
+        BatchDataset {
+            data: self.data.par_iter().map(f).collect(),
+        }
     }
 
-    pub fn data(&self) -> &[T] {
-        &self.data
+    pub fn filter<F>(&self, predicate: F) -> BatchDataset<T>
+    where
+        F: Fn(&T) -> bool + Sync + Send,
+    {
+        BatchDataset {
+            data: self
+                .data
+                .par_iter()
+                .filter(|x| predicate(x))
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn reduce<F>(&self, init: T, f: F) -> T
+    where
+        T: Send,
+        F: Fn(T, &T) -> T + Sync + Send,
+    {
+        self.data
+            .par_iter()
+            .fold(|| init.clone(), |acc, x| f(acc, x))
+            .reduce(|| init.clone(), |a, b| f(a, &b))
     }
 }
 
@@ -109,10 +168,14 @@ impl<T: Clone> StreamDataset<T> {
     }
 }
 
-/// Graph dataset representation
+/// Graph dataset representation. Each edge carries an optional ingestion
+/// timestamp, so a graph built from a temporal source (see
+/// `stream_to_graph`) can be sliced into time-windowed views without
+/// losing the untimed edges other paradigm transitions (e.g.
+/// `batch_to_graph`) still produce.
 pub struct GraphDataset {
     vertices: Vec<f64>,
-    edges: HashMap<usize, Vec<(usize, f64)>>,
+    edges: HashMap<usize, Vec<(usize, f64, Option<i64>)>>,
 }
 
 impl GraphDataset {
@@ -130,9 +193,19 @@ impl GraphDataset {
         id
     }
 
+    /// Adds an untimed edge (no temporal information, so it never appears
+    /// in a `window` view).
     pub fn add_edge(&mut self, src: usize, dst: usize, weight: f64) {
         if let Some(adj) = self.edges.get_mut(&src) {
-            adj.push((dst, weight));
+            adj.push((dst, weight, None));
+        }
+    }
+
+    /// Adds an edge tagged with the time it was inserted, making it
+    /// eligible to appear in a `window(from, to)` view.
+    pub fn add_edge_at(&mut self, src: usize, dst: usize, weight: f64, timestamp: i64) {
+        if let Some(adj) = self.edges.get_mut(&src) {
+            adj.push((dst, weight, Some(timestamp)));
         }
     }
 
@@ -148,9 +221,32 @@ impl GraphDataset {
         self.vertices.get(id).copied().unwrap_or(0.0)
     }
 
-    pub fn neighbors(&self, id: usize) -> &[(usize, f64)] {
+    pub fn neighbors(&self, id: usize) -> &[(usize, f64, Option<i64>)] {
         self.edges.get(&id).map(|v| v.as_slice()).unwrap_or(&[])
     }
+
+    /// Projects a view containing only edges timestamped within
+    /// `[from, to]`, mirroring time-addressed edge insertion in temporal
+    /// graph engines. Vertices are preserved (so edge endpoints keep the
+    /// same ids); untimed edges are dropped since they have no time to
+    /// filter on.
+    pub fn window(&self, from: i64, to: i64) -> GraphDataset {
+        let mut windowed = GraphDataset {
+            vertices: self.vertices.clone(),
+            edges: HashMap::new(),
+        };
+
+        for (&src, adj) in &self.edges {
+            let filtered = adj
+                .iter()
+                .filter(|&&(_, _, ts)| matches!(ts, Some(t) if t >= from && t <= to))
+                .cloned()
+                .collect();
+            windowed.edges.insert(src, filtered);
+        }
+
+        windowed
+    }
 }
 
 impl Default for GraphDataset {
@@ -174,14 +270,15 @@ pub fn stream_to_batch<T: Clone>(stream: &StreamDataset<T>) -> BatchDataset<T> {
 }
 
 /// Transform batch to graph (correlation-based)
+#[cfg(not(feature = "parallel"))]
 pub fn batch_to_graph(batch: &BatchDataset<f64>, threshold: f64) -> GraphDataset {
     let mut graph = GraphDataset::new();
-    
+
     // Create vertices
     for &value in batch.data() {
         graph.add_vertex(value);
     }
-    
+
     // Create edges based on value proximity
     let data = batch.data();
     for i in 0..data.len() {
@@ -194,7 +291,47 @@ pub fn batch_to_graph(batch: &BatchDataset<f64>, threshold: f64) -> GraphDataset
             }
         }
     }
-    
+
+    graph
+}
+
+/// Transform batch to graph (correlation-based), distributing the O(n²)
+/// pairwise comparison across a parallel range: each rayon task folds its
+/// share of pairs into a thread-local edge buffer, and the buffers are
+/// merged (`reduce`) into one `Vec` before being applied to the graph, so
+/// `GraphDataset::add_edge` itself is never called from multiple threads.
+#[cfg(feature = "parallel")]
+pub fn batch_to_graph(batch: &BatchDataset<f64>, threshold: f64) -> GraphDataset {
+    let mut graph = GraphDataset::new();
+
+    // Create vertices
+    for &value in batch.data() {
+        graph.add_vertex(value);
+    }
+
+    let data = batch.data();
+    let edges: Vec<(usize, usize, f64)> = (0..data.len())
+        .into_par_iter()
+        .fold(Vec::new, |mut local: Vec<(usize, usize, f64)>, i| {
+            for j in (i + 1)..data.len() {
+                let diff = (data[i] - data[j]).abs();
+                if diff < threshold {
+                    let weight = 1.0 / (diff + 0.001);
+                    local.push((i, j, weight));
+                }
+            }
+            local
+        })
+        .reduce(Vec::new, |mut a, mut b| {
+            a.append(&mut b);
+            a
+        });
+
+    for (i, j, weight) in edges {
+        graph.add_edge(i, j, weight);
+        graph.add_edge(j, i, weight);
+    }
+
     graph
 }
 
@@ -212,7 +349,7 @@ pub fn graph_to_batch(graph: &GraphDataset) -> BatchDataset<f64> {
         while let Some(v) = queue.pop_front() {
             result.push(graph.vertex_value(v));
             
-            for &(neighbor, _) in graph.neighbors(v) {
+            for &(neighbor, _, _) in graph.neighbors(v) {
                 if !visited[neighbor] {
                     visited[neighbor] = true;
                     queue.push_back(neighbor);
@@ -231,36 +368,416 @@ pub fn graph_to_batch(graph: &GraphDataset) -> BatchDataset<f64> {
     BatchDataset::new(result)
 }
 
-/// Stream to graph (windowed correlation)
-pub fn stream_to_graph(stream: &StreamDataset<f64>, window_step: usize) -> GraphDataset {
+/// Stream to graph (temporal edge insertion).
+///
+/// Unlike `batch_to_graph`'s static correlation graph, this ingests
+/// `(value, timestamp)` stream items directly instead of averaging
+/// positional windows: each item becomes a vertex, and each consecutive
+/// pair becomes an edge tagged with the ingestion time of the later event.
+/// That keeps event ordering visible in the graph paradigm and lets
+/// `GraphDataset::window` slice the result into time-bounded traversals
+/// rather than only a single static snapshot.
+pub fn stream_to_graph(stream: &StreamDataset<(f64, i64)>) -> GraphDataset {
     let mut graph = GraphDataset::new();
-    let window: Vec<f64> = stream.window().iter().cloned().collect();
-    
-    if window.len() < window_step * 2 {
-        return graph;
+    let window: Vec<(f64, i64)> = stream.window().iter().cloned().collect();
+
+    for &(value, _) in &window {
+        graph.add_vertex(value);
     }
-    
-    // Create nodes from aggregated windows
-    let num_nodes = window.len() / window_step;
-    for i in 0..num_nodes {
-        let start = i * window_step;
-        let end = (start + window_step).min(window.len());
-        let avg: f64 = window[start..end].iter().sum::<f64>() / (end - start) as f64;
-        graph.add_vertex(avg);
+
+    for i in 0..window.len().saturating_sub(1) {
+        let (value, _) = window[i];
+        let (next_value, next_ts) = window[i + 1];
+        let weight = 1.0 / ((value - next_value).abs() + 0.1);
+        graph.add_edge_at(i, i + 1, weight, next_ts);
     }
-    
-    // Create edges based on temporal correlation
-    for i in 0..num_nodes {
-        for j in (i + 1)..num_nodes {
-            let vi = graph.vertex_value(i);
-            let vj = graph.vertex_value(j);
-            let correlation = 1.0 / ((vi - vj).abs() + 0.1);
-            if correlation > 1.0 {
-                graph.add_edge(i, j, correlation);
+
+    graph
+}
+
+// ============================================================================
+// Zero-Copy Columnar Backing (Arrow)
+// ============================================================================
+
+/// An `f64` column backed by an Arrow `Float64Array`, shared via `Arc` so a
+/// batch and any stream window or chunk carved out of it are offset/length
+/// views over the *same* buffer rather than separate allocations. This is
+/// the zero-copy counterpart to [`BatchDataset`]/[`StreamDataset`]'s
+/// `Vec`-owning representation: [`batch_to_stream_view`] and
+/// [`stream_to_batch_view`] produce `ColumnView`s instead of the clones
+/// `batch_to_stream`/`stream_to_batch` allocate.
+#[cfg(feature = "arrow-backend")]
+#[derive(Clone)]
+pub struct ColumnView {
+    buffer: Arc<Float64Array>,
+    offset: usize,
+    len: usize,
+}
+
+#[cfg(feature = "arrow-backend")]
+impl ColumnView {
+    /// Wraps `data` in a fresh Arrow buffer — the one real allocation in a
+    /// transition chain; every view taken from it afterwards is free.
+    pub fn from_vec(data: Vec<f64>) -> Self {
+        let len = data.len();
+        Self {
+            buffer: Arc::new(Float64Array::from(data)),
+            offset: 0,
+            len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Slices out `[offset, offset + len)` of this view. An `Arc` clone plus
+    /// two `usize`s — never a reallocation or a copy of the underlying
+    /// values.
+    pub fn view(&self, offset: usize, len: usize) -> Self {
+        assert!(offset + len <= self.len, "view out of bounds");
+        Self {
+            buffer: Arc::clone(&self.buffer),
+            offset: self.offset + offset,
+            len,
+        }
+    }
+
+    /// A strided iterator over the view: the end bound is cached once up
+    /// front and each step reads the backing buffer directly, so iteration
+    /// stays competitive with a plain `Vec`/slice iterator despite the
+    /// extra `Arc` indirection.
+    pub fn iter(&self) -> ColumnIter<'_> {
+        ColumnIter {
+            buffer: &self.buffer,
+            pos: self.offset,
+            end: self.offset + self.len,
+        }
+    }
+}
+
+#[cfg(feature = "arrow-backend")]
+pub struct ColumnIter<'a> {
+    buffer: &'a Float64Array,
+    pos: usize,
+    end: usize,
+}
+
+#[cfg(feature = "arrow-backend")]
+impl<'a> Iterator for ColumnIter<'a> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.pos >= self.end {
+            return None;
+        }
+        // SAFETY: `pos` stays within `[offset, offset + len)`, which was
+        // bounds-checked against the backing buffer at construction/`view`.
+        let value = unsafe { self.buffer.value_unchecked(self.pos) };
+        self.pos += 1;
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.pos;
+        (remaining, Some(remaining))
+    }
+}
+
+/// Zero-copy counterpart to [`batch_to_stream`]: instead of a `Vec<Vec<f64>>`
+/// of owned chunks, returns `chunk_size`-wide views into `batch`'s existing
+/// buffer.
+#[cfg(feature = "arrow-backend")]
+pub fn batch_to_stream_view(batch: &ColumnView, chunk_size: usize) -> Vec<ColumnView> {
+    let chunk_size = chunk_size.max(1);
+    let mut chunks = Vec::with_capacity((batch.len() + chunk_size - 1) / chunk_size);
+    let mut offset = 0;
+    while offset < batch.len() {
+        let len = chunk_size.min(batch.len() - offset);
+        chunks.push(batch.view(offset, len));
+        offset += len;
+    }
+    chunks
+}
+
+/// Zero-copy counterpart to [`stream_to_batch`]: a stream window is already
+/// a contiguous `ColumnView`, so this is an `Arc` clone, not a fresh `Vec`.
+#[cfg(feature = "arrow-backend")]
+pub fn stream_to_batch_view(window: &ColumnView) -> ColumnView {
+    window.clone()
+}
+
+// ============================================================================
+// Path-Aggregate Queries (Heavy-Light Decomposition + Fenwick Tree)
+// ============================================================================
+
+/// Builds an undirected adjacency list from a `GraphDataset`'s edges,
+/// treating it as the (spanning) tree `build_hld` decomposes. Edges are
+/// added to both endpoints' lists regardless of which direction they were
+/// originally inserted in, so this works whether the source graph stored
+/// each tree edge once or twice.
+fn build_adjacency(graph: &GraphDataset) -> Vec<Vec<usize>> {
+    let n = graph.vertex_count();
+    let mut adj: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for v in 0..n {
+        for &(u, _, _) in graph.neighbors(v) {
+            adj[v].push(u);
+            adj[u].push(v);
+        }
+    }
+    adj
+}
+
+/// First HLD pass: an iterative DFS (explicit stack, so depth is bounded
+/// only by heap, not call stack) from `root` that records each node's
+/// parent and depth, then a reverse pass over the DFS order to accumulate
+/// subtree sizes bottom-up.
+fn compute_sizes(
+    adj: &[Vec<usize>],
+    root: usize,
+    n: usize,
+) -> (Vec<usize>, Vec<Option<usize>>, Vec<usize>) {
+    let mut parent: Vec<Option<usize>> = vec![None; n];
+    let mut depth = vec![0usize; n];
+    let mut visited = vec![false; n];
+    let mut order = Vec::with_capacity(n);
+
+    let mut stack = vec![root];
+    visited[root] = true;
+    while let Some(v) = stack.pop() {
+        order.push(v);
+        for &u in &adj[v] {
+            if !visited[u] {
+                visited[u] = true;
+                parent[u] = Some(v);
+                depth[u] = depth[v] + 1;
+                stack.push(u);
+            }
+        }
+    }
+
+    let mut size = vec![1usize; n];
+    for &v in order.iter().rev() {
+        if let Some(p) = parent[v] {
+            size[p] += size[v];
+        }
+    }
+
+    (size, parent, depth)
+}
+
+/// A Fenwick tree (binary indexed tree) over `f64` addition, giving O(log n)
+/// point update and O(log n) prefix/range sum. Backs [`PathQuery`]'s
+/// per-chain aggregate folds.
+struct Fenwick {
+    tree: Vec<f64>,
+}
+
+impl Fenwick {
+    fn new(n: usize) -> Self {
+        Self {
+            tree: vec![0.0; n + 1],
+        }
+    }
+
+    fn add(&mut self, index: usize, delta: f64) {
+        let mut i = index + 1;
+        while i < self.tree.len() {
+            self.tree[i] += delta;
+            i += i & i.wrapping_neg();
+        }
+    }
+
+    fn prefix_sum(&self, index: usize) -> f64 {
+        let mut i = index + 1;
+        let mut sum = 0.0;
+        while i > 0 {
+            sum += self.tree[i];
+            i -= i & i.wrapping_neg();
+        }
+        sum
+    }
+
+    fn range_sum(&self, lo: usize, hi: usize) -> f64 {
+        if lo == 0 {
+            self.prefix_sum(hi)
+        } else {
+            self.prefix_sum(hi) - self.prefix_sum(lo - 1)
+        }
+    }
+}
+
+/// A Heavy-Light Decomposition over a `GraphDataset` tree, answering
+/// path-sum queries and point updates in O(log² n) and O(log n)
+/// respectively instead of the O(n) a naive per-node walk pays. See
+/// [`build_hld`] for construction.
+pub struct PathQuery {
+    parent: Vec<Option<usize>>,
+    depth: Vec<usize>,
+    head: Vec<usize>,
+    id: Vec<usize>,
+    values: Vec<f64>,
+    fenwick: Fenwick,
+}
+
+impl PathQuery {
+    /// Sets `node`'s aggregate value, folding the delta into its Fenwick
+    /// slot in O(log n).
+    pub fn update(&mut self, node: usize, value: f64) {
+        let delta = value - self.values[node];
+        self.values[node] = value;
+        self.fenwick.add(self.id[node], delta);
+    }
+
+    /// Sums every node's value on the path between `u` and `v`.
+    ///
+    /// Walks `u` and `v` up their chains, always advancing whichever
+    /// endpoint's chain head is deeper (breaking ties arbitrarily), folding
+    /// the BIT range covering each chain segment it crosses, until both
+    /// endpoints share a chain — at which point one final range covers the
+    /// rest. Each of the O(log n) chains crossed costs an O(log n) Fenwick
+    /// range query, for O(log² n) total.
+    pub fn query_path(&self, mut u: usize, mut v: usize) -> f64 {
+        let mut acc = 0.0;
+
+        while self.head[u] != self.head[v] {
+            if self.depth[self.head[u]] < self.depth[self.head[v]] {
+                std::mem::swap(&mut u, &mut v);
+            }
+
+            let top = self.head[u];
+            acc += self.fenwick.range_sum(self.id[top], self.id[u]);
+            u = self.parent[top].expect(
+                "top of a chain reached while heads differ is never the tree root",
+            );
+        }
+
+        let (lo, hi) = if self.id[u] <= self.id[v] {
+            (u, v)
+        } else {
+            (v, u)
+        };
+        acc += self.fenwick.range_sum(self.id[lo], self.id[hi]);
+
+        acc
+    }
+}
+
+/// Runs the HLD build described in the module docs — subtree sizes, heavy
+/// children, then chain ids assigned heavy-child-first — over `graph`
+/// rooted at `root`, and seeds the backing Fenwick tree from each node's
+/// `vertex_value`.
+pub fn build_hld(graph: &GraphDataset, root: usize) -> PathQuery {
+    let n = graph.vertex_count();
+    let adj = build_adjacency(graph);
+    let (size, parent, depth) = compute_sizes(&adj, root, n);
+
+    let mut heavy: Vec<Option<usize>> = vec![None; n];
+    for v in 0..n {
+        if let Some(p) = parent[v] {
+            let is_heavier = match heavy[p] {
+                None => true,
+                Some(current) => size[v] > size[current],
+            };
+            if is_heavier {
+                heavy[p] = Some(v);
+            }
+        }
+    }
+
+    let mut id = vec![0usize; n];
+    let mut head = vec![0usize; n];
+    let mut next_id = 0usize;
+    let mut chain_starts = vec![root];
+
+    while let Some(start) = chain_starts.pop() {
+        let mut v = start;
+        loop {
+            head[v] = start;
+            id[v] = next_id;
+            next_id += 1;
+
+            for &u in &adj[v] {
+                if Some(u) != parent[v] && Some(u) != heavy[v] {
+                    chain_starts.push(u);
+                }
+            }
+
+            match heavy[v] {
+                Some(h) => v = h,
+                None => break,
+            }
+        }
+    }
+
+    let mut values = vec![0.0; n];
+    let mut fenwick = Fenwick::new(n);
+    for v in 0..n {
+        let value = graph.vertex_value(v);
+        values[v] = value;
+        fenwick.add(id[v], value);
+    }
+
+    PathQuery {
+        parent,
+        depth,
+        head,
+        id,
+        values,
+        fenwick,
+    }
+}
+
+/// Naive O(depth) path-sum baseline [`bench_path_aggregate_queries`]
+/// contrasts against `PathQuery`: walks both endpoints up to their LCA one
+/// parent pointer at a time, summing values directly with no Fenwick tree
+/// and no chain jumps.
+fn naive_query_path(
+    parent: &[Option<usize>],
+    depth: &[usize],
+    values: &[f64],
+    mut u: usize,
+    mut v: usize,
+) -> f64 {
+    let mut acc = 0.0;
+
+    while depth[u] > depth[v] {
+        acc += values[u];
+        u = parent[u].expect("node with positive depth has a parent");
+    }
+    while depth[v] > depth[u] {
+        acc += values[v];
+        v = parent[v].expect("node with positive depth has a parent");
+    }
+    while u != v {
+        acc += values[u] + values[v];
+        u = parent[u].expect("tree is connected, so u and v share the root as an ancestor");
+        v = parent[v].expect("tree is connected, so u and v share the root as an ancestor");
+    }
+
+    acc + values[u]
+}
+
+/// Builds a complete binary tree with `n` vertices (node `i`'s children are
+/// `2i + 1` and `2i + 2`), each valued at its index — deep and branchy
+/// enough to exercise HLD's actual chain decomposition rather than
+/// degenerating to a single chain.
+fn generate_balanced_tree(n: usize) -> GraphDataset {
+    let mut graph = GraphDataset::new();
+    for i in 0..n {
+        graph.add_vertex(i as f64);
+    }
+    for i in 0..n {
+        for child in [2 * i + 1, 2 * i + 2] {
+            if child < n {
+                graph.add_edge(i, child, 1.0);
             }
         }
     }
-    
     graph
 }
 
@@ -292,15 +809,17 @@ impl UnifiedProcessor {
         // Phase 2: Stream processing
         let chunks = batch_to_stream(&processed_batch, 100);
         let mut stream = StreamDataset::new(1000);
+        let mut ingestion_time: i64 = 0;
         for chunk in chunks {
             for item in chunk {
-                stream.push(item);
+                stream.push((item, ingestion_time));
+                ingestion_time += 1;
             }
         }
         self.advance_epoch();
-        
+
         // Phase 3: Graph processing
-        let graph = stream_to_graph(&stream, 10);
+        let graph = stream_to_graph(&stream);
         self.advance_epoch();
         
         // Phase 4: Back to batch
@@ -399,232 +918,586 @@ impl TraditionalProcessor {
 // Benchmark Functions
 // ============================================================================
 
-fn generate_data(size: usize) -> Vec<f64> {
-    (0..size)
-        .map(|i| (i as f64).sin() * 100.0 + (i as f64).cos() * 50.0)
-        .collect()
+/// Fixed seed so every `generate_dataset` draw is reproducible across
+/// machines and CI runs — a benchmark rerun with the same `(size, dist)`
+/// always sees the same values.
+const SEED: u64 = 0x5EED_D474_5E17;
+
+/// Value distribution a generated dataset is drawn from. `Uniform` and
+/// `Normal` are seeded draws from `Xoshiro256Plus`; `Periodic` is the
+/// original deterministic sin/cos signal, kept so existing comparisons
+/// against it stay available.
+#[derive(Debug, Clone, Copy)]
+pub enum DataDistribution {
+    /// Values spread uniformly over `[-150, 150]`, matching the rough range
+    /// of the old sin/cos signal.
+    Uniform,
+    /// Values clustered around 0 with a standard deviation of 50 —
+    /// `batch_to_graph`'s proximity-threshold edge construction is far more
+    /// sensitive to this clustering than to a spread-out distribution.
+    Normal,
+    /// The original deterministic periodic signal.
+    Periodic,
+}
+
+impl DataDistribution {
+    /// Every distribution, for benchmark groups that sweep all of them.
+    pub const ALL: [DataDistribution; 3] = [
+        DataDistribution::Uniform,
+        DataDistribution::Normal,
+        DataDistribution::Periodic,
+    ];
+
+    /// Lowercase label used in `BenchmarkId`s so `criterion`'s report
+    /// distinguishes runs by distribution.
+    fn label(self) -> &'static str {
+        match self {
+            DataDistribution::Uniform => "uniform",
+            DataDistribution::Normal => "normal",
+            DataDistribution::Periodic => "periodic",
+        }
+    }
+}
+
+/// Generates a reproducible dataset of `size` values drawn from `dist`,
+/// seeded from `seed`. `Uniform`/`Normal` draw from a `Xoshiro256Plus` PRNG;
+/// `Periodic` ignores `seed` since the sin/cos signal is already
+/// deterministic.
+fn generate_dataset(size: usize, seed: u64, dist: DataDistribution) -> Vec<f64> {
+    match dist {
+        DataDistribution::Periodic => (0..size)
+            .map(|i| (i as f64).sin() * 100.0 + (i as f64).cos() * 50.0)
+            .collect(),
+        DataDistribution::Uniform => {
+            let mut rng = Xoshiro256Plus::seed_from_u64(seed);
+            let uniform = Uniform::new(-150.0, 150.0);
+            (0..size).map(|_| uniform.sample(&mut rng)).collect()
+        }
+        DataDistribution::Normal => {
+            let mut rng = Xoshiro256Plus::seed_from_u64(seed);
+            let normal = Normal::new(0.0, 50.0).expect("finite mean and positive std-dev");
+            (0..size).map(|_| normal.sample(&mut rng)).collect()
+        }
+    }
 }
 
 fn bench_paradigm_transitions(c: &mut Criterion) {
     let mut group = c.benchmark_group("paradigm_transitions");
-    
-    for size in [100, 1_000, 10_000] {
-        group.throughput(Throughput::Elements(size as u64));
-        
-        // Batch to Stream
-        group.bench_with_input(
-            BenchmarkId::new("batch_to_stream", size),
-            &size,
-            |b, &size| {
-                b.iter_batched(
-                    || BatchDataset::new(generate_data(size)),
-                    |batch| {
-                        let chunks = batch_to_stream(&batch, 100);
-                        black_box(chunks.len())
-                    },
-                    BatchSize::LargeInput,
-                )
-            },
-        );
-        
-        // Stream to Batch
-        group.bench_with_input(
-            BenchmarkId::new("stream_to_batch", size),
-            &size,
-            |b, &size| {
-                b.iter_batched(
-                    || {
-                        let mut stream = StreamDataset::new(size);
-                        for item in generate_data(size) {
-                            stream.push(item);
-                        }
-                        stream
+
+    for dist in DataDistribution::ALL {
+        for size in [100, 1_000, 10_000] {
+            group.throughput(Throughput::Elements(size as u64));
+
+            // Batch to Stream
+            group.bench_with_input(
+                BenchmarkId::new(format!("batch_to_stream_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || BatchDataset::new(generate_dataset(size, SEED, dist)),
+                        |batch| {
+                            let chunks = batch_to_stream(&batch, 100);
+                            black_box(chunks.len())
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            // Stream to Batch
+            group.bench_with_input(
+                BenchmarkId::new(format!("stream_to_batch_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || {
+                            let mut stream = StreamDataset::new(size);
+                            for item in generate_dataset(size, SEED, dist) {
+                                stream.push(item);
+                            }
+                            stream
+                        },
+                        |stream| {
+                            let batch = stream_to_batch(&stream);
+                            black_box(batch.len())
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            // Batch to Graph (smaller sizes due to O(n²)) — this is the
+            // transition most sensitive to `dist`: a clustered (`Normal`)
+            // distribution crosses the proximity threshold far more often
+            // than a spread-out (`Uniform`) one, so edge count (and cost)
+            // diverges sharply between the two at the same `size`.
+            if size <= 1000 {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("batch_to_graph_{}", dist.label()), size),
+                    &size,
+                    |b, &size| {
+                        b.iter_batched(
+                            || BatchDataset::new(generate_dataset(size, SEED, dist)),
+                            |batch| {
+                                let graph = batch_to_graph(&batch, 50.0);
+                                black_box((graph.vertex_count(), graph.edge_count()))
+                            },
+                            BatchSize::SmallInput,
+                        )
                     },
-                    |stream| {
-                        let batch = stream_to_batch(&stream);
-                        black_box(batch.len())
+                );
+            }
+
+            // Graph to Batch
+            if size <= 1000 {
+                group.bench_with_input(
+                    BenchmarkId::new(format!("graph_to_batch_{}", dist.label()), size),
+                    &size,
+                    |b, &size| {
+                        b.iter_batched(
+                            || {
+                                batch_to_graph(
+                                    &BatchDataset::new(generate_dataset(size, SEED, dist)),
+                                    50.0,
+                                )
+                            },
+                            |graph| {
+                                let batch = graph_to_batch(&graph);
+                                black_box(batch.len())
+                            },
+                            BatchSize::SmallInput,
+                        )
                     },
-                    BatchSize::LargeInput,
-                )
-            },
-        );
-        
-        // Batch to Graph (smaller sizes due to O(n²))
-        if size <= 1000 {
+                );
+            }
+        }
+    }
+
+    group.finish();
+}
+
+fn bench_unified_pipeline(c: &mut Criterion) {
+    let mut group = c.benchmark_group("unified_pipeline");
+
+    for dist in DataDistribution::ALL {
+        for size in [100, 1_000, 10_000] {
+            group.throughput(Throughput::Elements(size as u64));
+
+            // NEXUS unified approach
             group.bench_with_input(
-                BenchmarkId::new("batch_to_graph", size),
+                BenchmarkId::new(format!("nexus_unified_{}", dist.label()), size),
                 &size,
                 |b, &size| {
+                    let processor = UnifiedProcessor::new();
+
                     b.iter_batched(
-                        || BatchDataset::new(generate_data(size)),
-                        |batch| {
-                            let graph = batch_to_graph(&batch, 50.0);
-                            black_box((graph.vertex_count(), graph.edge_count()))
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            let result = processor.execute_pipeline(data);
+                            black_box(result.len())
                         },
-                        BatchSize::SmallInput,
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            // Traditional isolated processing
+            group.bench_with_input(
+                BenchmarkId::new(format!("traditional_isolated_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            // Sequential isolated processing
+                            let batch_result = TraditionalProcessor::process_batch(data);
+                            let stream_result =
+                                TraditionalProcessor::process_stream(batch_result.clone(), 10);
+                            let graph_result = if size <= 1000 {
+                                TraditionalProcessor::process_graph(stream_result)
+                            } else {
+                                stream_result
+                            };
+                            black_box(graph_result.len())
+                        },
+                        BatchSize::LargeInput,
                     )
                 },
             );
         }
-        
-        // Graph to Batch
-        if size <= 1000 {
+    }
+
+    group.finish();
+}
+
+fn bench_individual_paradigms(c: &mut Criterion) {
+    let mut group = c.benchmark_group("individual_paradigms");
+    let size = 10_000;
+
+    group.throughput(Throughput::Elements(size as u64));
+
+    for dist in DataDistribution::ALL {
+        // Pure batch processing
+        group.bench_function(format!("batch_only_{}", dist.label()), |b| {
+            b.iter_batched(
+                || generate_dataset(size, SEED, dist),
+                |data| {
+                    let batch = BatchDataset::new(data);
+                    let result = batch.map(|x| x * 2.0).filter(|x| *x > 0.0);
+                    let sum = result.reduce(0.0, |acc, x| acc + x);
+                    black_box(sum)
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        // Pure stream processing
+        group.bench_function(format!("stream_only_{}", dist.label()), |b| {
+            b.iter_batched(
+                || generate_dataset(size, SEED, dist),
+                |data| {
+                    let mut stream: StreamDataset<f64> = StreamDataset::new(1000);
+                    let mut sum = 0.0;
+
+                    for item in data {
+                        stream.push(item);
+                        sum += stream.aggregate(0.0, |acc, x| acc + x);
+                    }
+
+                    black_box(sum)
+                },
+                BatchSize::LargeInput,
+            )
+        });
+
+        // Pure graph processing (smaller size)
+        group.bench_function(format!("graph_only_{}", dist.label()), |b| {
+            b.iter_batched(
+                || generate_dataset(1000, SEED, dist),
+                |data| {
+                    let batch = BatchDataset::new(data);
+                    let graph = batch_to_graph(&batch, 50.0);
+                    black_box((graph.vertex_count(), graph.edge_count()))
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_transition_overhead(c: &mut Criterion) {
+    let mut group = c.benchmark_group("transition_overhead");
+
+    // Measure pure transformation overhead
+    for dist in DataDistribution::ALL {
+        for size in [100, 1_000, 10_000] {
+            group.bench_with_input(
+                BenchmarkId::new(format!("zero_copy_transform_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            // Zero-copy: just wrap data
+                            let batch = BatchDataset::new(data);
+                            black_box(batch.len())
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
             group.bench_with_input(
-                BenchmarkId::new("graph_to_batch", size),
+                BenchmarkId::new(format!("copy_transform_{}", dist.label()), size),
                 &size,
                 |b, &size| {
                     b.iter_batched(
-                        || batch_to_graph(&BatchDataset::new(generate_data(size)), 50.0),
-                        |graph| {
-                            let batch = graph_to_batch(&graph);
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            // With copy: clone data
+                            let batch = BatchDataset::new(data.clone());
                             black_box(batch.len())
                         },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+/// Serial vs. parallel throughput for `BatchDataset::map`/`reduce`, so the
+/// "15× faster" claim can be checked under multiple cores rather than just
+/// against `TraditionalProcessor`.
+#[cfg(feature = "parallel")]
+fn bench_parallel_batch_ops(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_batch_ops");
+
+    for dist in DataDistribution::ALL {
+        for size in [1_000, 10_000, 100_000] {
+            group.throughput(Throughput::Elements(size as u64));
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("map_serial_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            let result: Vec<f64> = data.iter().map(|x| x * 2.0).collect();
+                            black_box(result.len())
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("map_parallel_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            let result: Vec<f64> = data.par_iter().map(|x| x * 2.0).collect();
+                            black_box(result.len())
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("reduce_serial_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            let sum = data.iter().fold(0.0, |acc, x| acc + x);
+                            black_box(sum)
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("reduce_parallel_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| {
+                            let sum = data
+                                .par_iter()
+                                .fold(|| 0.0, |acc, x| acc + x)
+                                .reduce(|| 0.0, |a, b| a + b);
+                            black_box(sum)
+                        },
+                        BatchSize::LargeInput,
+                    )
+                },
+            );
+        }
+    }
+
+    group.finish();
+}
+
+/// Serial vs. parallel throughput for `batch_to_graph`'s O(n²) edge
+/// construction, the paradigm transition the data-parallel path targets.
+#[cfg(feature = "parallel")]
+fn bench_parallel_graph_construction(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_graph_construction");
+
+    // Both variants reproduce `batch_to_graph`'s pairwise comparison
+    // directly (rather than calling the feature-swapped function), so
+    // serial and parallel are always compared side by side regardless of
+    // which `batch_to_graph` the `parallel` feature activated.
+    fn serial_edges(data: &[f64], threshold: f64) -> usize {
+        let mut count = 0;
+        for i in 0..data.len() {
+            for j in (i + 1)..data.len() {
+                if (data[i] - data[j]).abs() < threshold {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+
+    fn parallel_edges(data: &[f64], threshold: f64) -> usize {
+        (0..data.len())
+            .into_par_iter()
+            .fold(
+                || 0usize,
+                |count, i| {
+                    count
+                        + (i + 1..data.len())
+                            .filter(|&j| (data[i] - data[j]).abs() < threshold)
+                            .count()
+                },
+            )
+            .reduce(|| 0usize, |a, b| a + b)
+    }
+
+    for dist in DataDistribution::ALL {
+        for size in [100, 500, 1_000, 2_000] {
+            group.throughput(Throughput::Elements(size as u64));
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("batch_to_graph_serial_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| black_box(serial_edges(&data, 50.0)),
+                        BatchSize::SmallInput,
+                    )
+                },
+            );
+
+            group.bench_with_input(
+                BenchmarkId::new(format!("batch_to_graph_parallel_{}", dist.label()), size),
+                &size,
+                |b, &size| {
+                    b.iter_batched(
+                        || generate_dataset(size, SEED, dist),
+                        |data| black_box(parallel_edges(&data, 50.0)),
                         BatchSize::SmallInput,
                     )
                 },
             );
         }
     }
-    
+
     group.finish();
 }
 
-fn bench_unified_pipeline(c: &mut Criterion) {
-    let mut group = c.benchmark_group("unified_pipeline");
-    
-    for size in [100, 1_000, 10_000] {
+/// `PathQuery`'s O(log² n) HLD+Fenwick path sum vs. `naive_query_path`'s
+/// O(depth) walk, over the same balanced tree and endpoints, to demonstrate
+/// the logarithmic transition cost the crate advertises.
+fn bench_path_aggregate_queries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("path_aggregate_queries");
+
+    for size in [100, 1_000, 10_000, 100_000] {
         group.throughput(Throughput::Elements(size as u64));
-        
-        // NEXUS unified approach
+
         group.bench_with_input(
-            BenchmarkId::new("nexus_unified", size),
+            BenchmarkId::new("hld_fenwick", size),
             &size,
             |b, &size| {
-                let processor = UnifiedProcessor::new();
-                
                 b.iter_batched(
-                    || generate_data(size),
-                    |data| {
-                        let result = processor.execute_pipeline(data);
-                        black_box(result.len())
-                    },
-                    BatchSize::LargeInput,
+                    || build_hld(&generate_balanced_tree(size), 0),
+                    |query| black_box(query.query_path(0, size - 1)),
+                    BatchSize::SmallInput,
                 )
             },
         );
-        
-        // Traditional isolated processing
+
         group.bench_with_input(
-            BenchmarkId::new("traditional_isolated", size),
+            BenchmarkId::new("naive_path_walk", size),
             &size,
             |b, &size| {
                 b.iter_batched(
-                    || generate_data(size),
-                    |data| {
-                        // Sequential isolated processing
-                        let batch_result = TraditionalProcessor::process_batch(data);
-                        let stream_result = TraditionalProcessor::process_stream(batch_result.clone(), 10);
-                        let graph_result = if size <= 1000 {
-                            TraditionalProcessor::process_graph(stream_result)
-                        } else {
-                            stream_result
-                        };
-                        black_box(graph_result.len())
+                    || {
+                        let graph = generate_balanced_tree(size);
+                        let adj = build_adjacency(&graph);
+                        let (_, parent, depth) = compute_sizes(&adj, 0, size);
+                        let values: Vec<f64> = (0..size).map(|i| graph.vertex_value(i)).collect();
+                        (parent, depth, values)
                     },
-                    BatchSize::LargeInput,
+                    |(parent, depth, values)| {
+                        black_box(naive_query_path(&parent, &depth, &values, 0, size - 1))
+                    },
+                    BatchSize::SmallInput,
                 )
             },
         );
     }
-    
+
     group.finish();
 }
 
-fn bench_individual_paradigms(c: &mut Criterion) {
-    let mut group = c.benchmark_group("individual_paradigms");
-    let size = 10_000;
-    
-    group.throughput(Throughput::Elements(size as u64));
-    
-    // Pure batch processing
-    group.bench_function("batch_only", |b| {
-        b.iter_batched(
-            || generate_data(size),
-            |data| {
-                let batch = BatchDataset::new(data);
-                let result = batch.map(|x| x * 2.0).filter(|x| *x > 0.0);
-                let sum = result.reduce(0.0, |acc, x| acc + x);
-                black_box(sum)
-            },
-            BatchSize::LargeInput,
-        )
-    });
-    
-    // Pure stream processing
-    group.bench_function("stream_only", |b| {
-        b.iter_batched(
-            || generate_data(size),
-            |data| {
-                let mut stream: StreamDataset<f64> = StreamDataset::new(1000);
-                let mut sum = 0.0;
-                
-                for item in data {
-                    stream.push(item);
-                    sum += stream.aggregate(0.0, |acc, x| acc + x);
-                }
-                
-                black_box(sum)
+/// Contrasts `batch_to_stream`/`stream_to_batch`'s `Vec`-cloning transitions
+/// against `batch_to_stream_view`/`stream_to_batch_view`'s `Arc`-sharing
+/// ones: the view-based timings should stay essentially flat as `size`
+/// grows, while the cloning ones scale with it, demonstrating the O(1)
+/// memory overhead per transition the columnar backing buys. Uses the fixed
+/// `Periodic` distribution throughout rather than sweeping `DataDistribution`
+/// like the transition benchmarks above — the `Arc`-sharing cost this group
+/// measures doesn't depend on how the values are distributed.
+#[cfg(feature = "arrow-backend")]
+fn bench_zero_copy_columnar(c: &mut Criterion) {
+    let mut group = c.benchmark_group("zero_copy_columnar");
+
+    for size in [1_000, 10_000, 100_000, 1_000_000] {
+        group.throughput(Throughput::Elements(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("batch_to_stream_clone", size),
+            &size,
+            |b, &size| {
+                b.iter_batched(
+                    || BatchDataset::new(generate_dataset(size, SEED, DataDistribution::Periodic)),
+                    |batch| {
+                        let chunks = batch_to_stream(&batch, 1_000);
+                        black_box(chunks.len())
+                    },
+                    BatchSize::LargeInput,
+                )
             },
-            BatchSize::LargeInput,
-        )
-    });
-    
-    // Pure graph processing (smaller size)
-    group.bench_function("graph_only", |b| {
-        b.iter_batched(
-            || generate_data(1000),
-            |data| {
-                let batch = BatchDataset::new(data);
-                let graph = batch_to_graph(&batch, 50.0);
-                black_box((graph.vertex_count(), graph.edge_count()))
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("batch_to_stream_view", size),
+            &size,
+            |b, &size| {
+                b.iter_batched(
+                    || ColumnView::from_vec(generate_dataset(size, SEED, DataDistribution::Periodic)),
+                    |batch| {
+                        let chunks = batch_to_stream_view(&batch, 1_000);
+                        black_box(chunks.len())
+                    },
+                    BatchSize::LargeInput,
+                )
             },
-            BatchSize::SmallInput,
-        )
-    });
-    
-    group.finish();
-}
+        );
 
-fn bench_transition_overhead(c: &mut Criterion) {
-    let mut group = c.benchmark_group("transition_overhead");
-    
-    // Measure pure transformation overhead
-    for size in [100, 1_000, 10_000] {
         group.bench_with_input(
-            BenchmarkId::new("zero_copy_transform", size),
+            BenchmarkId::new("stream_to_batch_clone", size),
             &size,
             |b, &size| {
                 b.iter_batched(
-                    || generate_data(size),
-                    |data| {
-                        // Zero-copy: just wrap data
-                        let batch = BatchDataset::new(data);
+                    || {
+                        let mut stream = StreamDataset::new(size);
+                        for item in generate_dataset(size, SEED, DataDistribution::Periodic) {
+                            stream.push(item);
+                        }
+                        stream
+                    },
+                    |stream| {
+                        let batch = stream_to_batch(&stream);
                         black_box(batch.len())
                     },
                     BatchSize::LargeInput,
                 )
             },
         );
-        
+
         group.bench_with_input(
-            BenchmarkId::new("copy_transform", size),
+            BenchmarkId::new("stream_to_batch_view", size),
             &size,
             |b, &size| {
                 b.iter_batched(
-                    || generate_data(size),
-                    |data| {
-                        // With copy: clone data
-                        let batch = BatchDataset::new(data.clone());
+                    || ColumnView::from_vec(generate_dataset(size, SEED, DataDistribution::Periodic)),
+                    |window| {
+                        let batch = stream_to_batch_view(&window);
                         black_box(batch.len())
                     },
                     BatchSize::LargeInput,
@@ -632,7 +1505,7 @@ fn bench_transition_overhead(c: &mut Criterion) {
             },
         );
     }
-    
+
     group.finish();
 }
 
@@ -640,12 +1513,33 @@ fn bench_transition_overhead(c: &mut Criterion) {
 // Criterion Configuration
 // ============================================================================
 
+#[cfg(not(feature = "parallel"))]
+criterion_group!(
+    cross_paradigm_benches,
+    bench_paradigm_transitions,
+    bench_unified_pipeline,
+    bench_individual_paradigms,
+    bench_transition_overhead,
+    bench_path_aggregate_queries,
+);
+
+#[cfg(feature = "parallel")]
 criterion_group!(
     cross_paradigm_benches,
     bench_paradigm_transitions,
     bench_unified_pipeline,
     bench_individual_paradigms,
     bench_transition_overhead,
+    bench_path_aggregate_queries,
+    bench_parallel_batch_ops,
+    bench_parallel_graph_construction,
 );
 
+#[cfg(feature = "arrow-backend")]
+criterion_group!(arrow_benches, bench_zero_copy_columnar);
+
+#[cfg(not(feature = "arrow-backend"))]
 criterion_main!(cross_paradigm_benches);
+
+#[cfg(feature = "arrow-backend")]
+criterion_main!(cross_paradigm_benches, arrow_benches);
@@ -16,16 +16,60 @@
 //! 6. Garbage from epoch e is collected when the global epoch reaches e + 2
 //! ```
 //!
+//! # Garbage Batching
+//!
+//! `defer()` never touches global state directly. Each participant retires
+//! into its own `local_garbage` bag with no cross-thread contention; once
+//! that bag reaches `MAX_OBJECTS`, it is stamped with the current global
+//! epoch and handed off (as a `SealedBag`) to a lock-free stack shared by
+//! all participants. `try_advance_and_collect()` drains that stack, running
+//! destructors for any bag stamped at least two epochs behind the current
+//! one and returning the rest for a later pass. This amortizes global
+//! synchronization over `MAX_OBJECTS` retirements instead of paying for it
+//! on every single one.
+//!
+//! # Explicit Registration
+//!
+//! `Collector` is a cheap, `Clone`-able handle to `Arc`-backed shared state.
+//! `pin()` keeps registering (and caching) a participant per thread the way
+//! it always has, but [`Collector::register`] hands that same registration
+//! out directly as a [`LocalHandle`] for callers whose reclamation contexts
+//! don't line up with OS threads — a `LocalHandle` outlives the `Collector`
+//! it was created from and keeps working regardless of what happens to that
+//! particular reference.
+//!
+//! # Repinning
+//!
+//! A guard held across a long operation holds back epoch advancement for
+//! as long as it's alive, since the collector can't tell whether it's still
+//! observing the epoch it was pinned at or has simply gone stale.
+//! [`Guard::repin`]/[`Guard::repin_after`] let a guard refresh to the current
+//! epoch without a full unpin/pin round-trip through the registry, so
+//! long-lived guards don't need to be dropped and reacquired just to stop
+//! blocking reclamation.
+//!
+//! # Sanitize Mode
+//!
+//! The `sanitize` feature trades throughput for a much tighter window on
+//! reclamation bugs: `MAX_OBJECTS` shrinks so bags seal (and retire) almost
+//! immediately, `GC_FREQUENCY` drops to 1 so every `pin()` attempts an
+//! advance-and-collect, and `try_advance()` checks participants in a
+//! perturbed order each call instead of a fixed head-to-tail walk. None of
+//! this changes correctness — it just makes races and ordering assumptions
+//! far more likely to surface under test or fuzzing.
+//!
 //! # Complexity
 //!
-//! - pin(): O(1) 
+//! - pin(): O(1)
 //! - unpin(): O(1) amortized
+//! - defer(): O(1) amortized (O(MAX_OBJECTS) on the rare sealing retirement)
 //! - collect(): O(G) where G is garbage count
 //! - try_advance(): O(T) where T is participant count
 
-use core::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use core::sync::atomic::{AtomicPtr, AtomicU64, AtomicUsize, Ordering};
 use core::cell::UnsafeCell;
-use core::mem::MaybeUninit;
+use core::mem::{self, MaybeUninit};
+use core::ptr;
 
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
@@ -33,13 +77,128 @@ use alloc::boxed::Box;
 #[cfg(feature = "std")]
 use std::boxed::Box;
 
-use super::{Epoch, AtomicEpoch, GarbageBag, Guard, INACTIVE};
+#[cfg(not(feature = "std"))]
+use alloc::sync::Arc;
 
-/// Maximum number of participants (threads) supported
-const MAX_PARTICIPANTS: usize = 256;
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use super::{Epoch, AtomicEpoch, GarbageBag, Guard, INACTIVE};
 
 /// Epochs between garbage collection attempts
+///
+/// Forced to 1 under `sanitize` so every `pin()` attempts an
+/// advance-and-collect, instead of only every `GC_FREQUENCY`th one.
+#[cfg(not(feature = "sanitize"))]
 const GC_FREQUENCY: u64 = 128;
+#[cfg(feature = "sanitize")]
+const GC_FREQUENCY: u64 = 1;
+
+/// Maximum number of objects a thread-local bag holds before it is sealed
+/// with the current epoch and handed off to the global queue.
+///
+/// Shrunk drastically under `sanitize` so bags seal (and their contents
+/// retire) far more often.
+#[cfg(not(feature = "sanitize"))]
+const MAX_OBJECTS: usize = 64;
+#[cfg(feature = "sanitize")]
+const MAX_OBJECTS: usize = 4;
+
+/// Monotonically increasing salt used to rotate the participant scan order
+/// in `sanitize` mode (see `Inner::sanitized_participant_order`).
+#[cfg(feature = "sanitize")]
+static SANITIZE_SCAN_SALT: AtomicUsize = AtomicUsize::new(0);
+
+/// Inline capacity (in machine words) for a deferred closure before it
+/// must be boxed.
+const DEFERRED_INLINE_WORDS: usize = 3;
+
+/// A type-erased `FnOnce()` callback deferred for later invocation.
+///
+/// Closures that fit in `DEFERRED_INLINE_WORDS` machine words and are no
+/// more aligned than a `usize` are stored inline, so retiring them costs no
+/// allocation; anything larger is boxed and the inline storage just holds
+/// the box's pointer. Either representation is invoked through the same
+/// `call` function pointer, so callers never need to know which applies.
+/// A `Deferred` is itself retired like any other garbage-bag entry, and
+/// its `Drop` impl is what actually runs the closure when the bag collects
+/// it.
+pub struct Deferred {
+    call: unsafe fn(*mut u8),
+    data: MaybeUninit<[usize; DEFERRED_INLINE_WORDS]>,
+}
+
+impl Deferred {
+    /// Wraps `f` for deferred execution.
+    pub fn new<F: FnOnce() + 'static>(f: F) -> Self {
+        // SAFETY: `F: 'static` satisfies `new_unchecked`'s lifetime
+        // requirement trivially.
+        unsafe { Self::new_unchecked(f) }
+    }
+
+    /// Like [`new`](Self::new), but `f` need not be `'static`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure `f` (and anything it borrows) remains valid
+    /// until this `Deferred` is invoked.
+    pub unsafe fn new_unchecked<F: FnOnce()>(f: F) -> Self {
+        let mut data = MaybeUninit::<[usize; DEFERRED_INLINE_WORDS]>::uninit();
+
+        if Self::fits_inline::<F>() {
+            // SAFETY: `fits_inline` guarantees `F` is no larger and no
+            // more aligned than `data`, and `data` is uninitialized so
+            // there is nothing to overwrite.
+            unsafe { (data.as_mut_ptr() as *mut F).write(f) };
+
+            unsafe fn call_inline<F: FnOnce()>(raw: *mut u8) {
+                // SAFETY: `raw` points at a live, inline-stored `F` that
+                // has not yet been read out.
+                let f = unsafe { ptr::read(raw as *mut F) };
+                f();
+            }
+
+            Self { call: call_inline::<F>, data }
+        } else {
+            let boxed: *mut F = Box::into_raw(Box::new(f));
+            // SAFETY: a `*mut F` always fits `DEFERRED_INLINE_WORDS`.
+            unsafe { (data.as_mut_ptr() as *mut *mut F).write(boxed) };
+
+            unsafe fn call_boxed<F: FnOnce()>(raw: *mut u8) {
+                // SAFETY: `raw` points at a live `*mut F` written above.
+                let boxed = unsafe { ptr::read(raw as *mut *mut F) };
+                // SAFETY: `boxed` came from `Box::into_raw` and has not
+                // been freed yet.
+                let f = unsafe { Box::from_raw(boxed) };
+                f();
+            }
+
+            Self { call: call_boxed::<F>, data }
+        }
+    }
+
+    fn fits_inline<F>() -> bool {
+        mem::size_of::<F>() <= mem::size_of::<[usize; DEFERRED_INLINE_WORDS]>()
+            && mem::align_of::<F>() <= mem::align_of::<usize>()
+    }
+}
+
+impl Drop for Deferred {
+    fn drop(&mut self) {
+        // SAFETY: `call` was produced alongside `data` in `new_unchecked`
+        // and is the only thing ever allowed to interpret its bytes; this
+        // runs exactly once, since `Drop::drop` itself only runs once.
+        unsafe { (self.call)(self.data.as_mut_ptr() as *mut u8) }
+    }
+}
+
+// SAFETY: `Deferred` is only ever constructed from an `F` the caller has
+// already asserted is safe to send across threads (`'static` for `new`,
+// or an explicit promise for `new_unchecked`).
+unsafe impl Send for Deferred {}
 
 /// The global garbage collector
 ///
@@ -47,6 +206,10 @@ const GC_FREQUENCY: u64 = 128;
 /// Each thread that needs to access shared data must register as a participant
 /// and pin itself before accessing protected data.
 ///
+/// `Collector` is itself just a cheaply-`Clone`-able handle around `Arc`-backed
+/// shared state, so every clone refers to the same registry, epoch counter and
+/// garbage queues.
+///
 /// # Thread Safety
 ///
 /// The collector uses lock-free algorithms internally and is safe to share
@@ -56,9 +219,8 @@ const GC_FREQUENCY: u64 = 128;
 ///
 /// ```rust
 /// use nexus_memory::Collector;
-/// use std::sync::Arc;
 ///
-/// let collector = Arc::new(Collector::new());
+/// let collector = Collector::new();
 ///
 /// // Clone for multiple threads
 /// let collector2 = collector.clone();
@@ -71,30 +233,44 @@ const GC_FREQUENCY: u64 = 128;
 /// let guard = collector.pin();
 /// // Protected access here
 /// ```
+#[derive(Clone)]
 pub struct Collector {
+    inner: Arc<Inner>,
+}
+
+/// The collector's shared state, held behind an `Arc` so it keeps living
+/// for as long as any `Collector` clone or [`LocalHandle`] refers to it,
+/// independent of which particular handle created it.
+struct Inner {
     /// The global epoch counter
-    pub(crate) global_epoch: AtomicEpoch,
-    
-    /// Participant registry - fixed array for lock-free access
-    participants: Box<[Participant; MAX_PARTICIPANTS]>,
-    
-    /// Number of registered participants
+    global_epoch: AtomicEpoch,
+
+    /// Head of the intrusive lock-free participant registry.
+    ///
+    /// New participants are pushed with a CAS on this pointer (crossbeam-style
+    /// registry); the list has no fixed capacity, so registering the 257th
+    /// concurrent thread no longer panics. A participant that exits unlinks
+    /// its own node rather than leaving a permanently "active" slot behind.
+    participants_head: AtomicPtr<Participant>,
+
+    /// Number of currently live participants
     num_participants: AtomicUsize,
-    
-    /// Garbage bags for each epoch (rotating)
-    garbage: [UnsafeCell<GarbageBag>; 4],
-    
+
+    /// Lock-free stack of epoch-stamped bags handed off by participants
+    /// whose thread-local bag filled up, awaiting collection.
+    sealed_bags: AtomicPtr<SealedBag>,
+
     /// Number of operations since last GC attempt
     ops_since_gc: AtomicU64,
-    
+
     /// Collection statistics
     #[cfg(feature = "statistics")]
     stats: CollectorStats,
 }
 
-// SAFETY: Collector uses proper synchronization internally
-unsafe impl Send for Collector {}
-unsafe impl Sync for Collector {}
+// SAFETY: Inner uses proper synchronization internally
+unsafe impl Send for Inner {}
+unsafe impl Sync for Inner {}
 
 /// Statistics for garbage collection (optional)
 #[cfg(feature = "statistics")]
@@ -110,23 +286,42 @@ struct CollectorStats {
     failed_advances: AtomicU64,
 }
 
+/// A thread-local garbage bag that filled up and was handed off to the
+/// global queue, stamped with the epoch during which it was sealed.
+///
+/// A bag's contents are only safe to destroy once the global epoch has
+/// advanced at least two epochs past `epoch`, matching the grace period
+/// the rest of the collector relies on.
+struct SealedBag {
+    epoch: Epoch,
+    bag: GarbageBag,
+    next: AtomicPtr<SealedBag>,
+}
+
 /// A participant in the epoch-based reclamation scheme
 ///
 /// Each thread that accesses protected data registers as a participant.
-/// The participant tracks the thread's current epoch status.
+/// The participant tracks the thread's current epoch status. Participants
+/// are heap-allocated nodes of the collector's intrusive registry list
+/// rather than slots in a fixed array, so they outlive the registry walk
+/// that may be iterating past them.
 #[repr(align(128))] // Cache line padded to prevent false sharing
 pub struct Participant {
     /// The epoch this participant last observed (INACTIVE if not pinned)
     pub(crate) epoch: AtomicEpoch,
-    
-    /// Whether this slot is in use
+
+    /// Whether this node is still linked into the live registry (1) or has
+    /// been unlinked and is only waiting for epoch-deferred reclamation (0)
     pub(crate) active: AtomicUsize,
-    
+
     /// Local garbage bag for this participant
     pub(crate) local_garbage: UnsafeCell<GarbageBag>,
-    
+
     /// Count of pins without unpins (for nested pinning)
     pub(crate) pin_count: AtomicUsize,
+
+    /// Next node in the registry's intrusive singly-linked list
+    next: AtomicPtr<Participant>,
 }
 
 impl Default for Participant {
@@ -136,6 +331,7 @@ impl Default for Participant {
             active: AtomicUsize::new(0),
             local_garbage: UnsafeCell::new(GarbageBag::new()),
             pin_count: AtomicUsize::new(0),
+            next: AtomicPtr::new(ptr::null_mut()),
         }
     }
 }
@@ -144,6 +340,46 @@ impl Default for Participant {
 unsafe impl Send for Participant {}
 unsafe impl Sync for Participant {}
 
+/// An explicit, non-thread-local handle to a registered participant.
+///
+/// `Collector::pin()` transparently registers (and caches) one of these per
+/// thread, but callers whose reclamation contexts don't line up with OS
+/// threads — migrating thread-pool workers, async tasks, or an explicitly
+/// managed pool of handles — can call [`Collector::register`] to get one
+/// directly and control exactly when it's created and torn down.
+///
+/// A `LocalHandle` holds a clone of the `Collector` it was registered with
+/// (and therefore a share of its `Arc`-backed state), so it keeps working
+/// even after the `Collector` reference that created it has been dropped.
+/// Its participant slot is released when the handle itself is dropped.
+pub struct LocalHandle {
+    collector: Collector,
+    node: *mut Participant,
+}
+
+impl LocalHandle {
+    /// Pins this handle's participant, returning a guard that protects access.
+    ///
+    /// While a guard is held, the current epoch's garbage will not be collected.
+    pub fn pin(&self) -> Guard<'_> {
+        // SAFETY: `node` stays linked (and thus valid) for as long as this
+        // handle is alive; it is only unlinked in `Drop`, below.
+        let participant = unsafe { &*self.node };
+        self.collector.pin_participant(participant)
+    }
+}
+
+impl Drop for LocalHandle {
+    fn drop(&mut self) {
+        // SAFETY: `node` was registered by `self.collector` and is unlinked
+        // at most once, here.
+        unsafe { self.collector.inner.unlink_participant(self.node) };
+    }
+}
+
+// SAFETY: the raw pointer only ever dereferences into atomics-guarded data.
+unsafe impl Send for LocalHandle {}
+
 impl Collector {
     /// Creates a new collector.
     ///
@@ -155,34 +391,32 @@ impl Collector {
     /// let collector = Collector::new();
     /// ```
     pub fn new() -> Self {
-        // Initialize participant array
-        let participants = {
-            let mut arr: Box<[MaybeUninit<Participant>; MAX_PARTICIPANTS]> = 
-                Box::new(unsafe { MaybeUninit::uninit().assume_init() });
-            
-            for slot in arr.iter_mut() {
-                slot.write(Participant::default());
-            }
-            
-            // SAFETY: All elements are initialized
-            unsafe {
-                Box::from_raw(Box::into_raw(arr) as *mut [Participant; MAX_PARTICIPANTS])
-            }
-        };
-        
         Self {
-            global_epoch: AtomicEpoch::new(0),
-            participants,
-            num_participants: AtomicUsize::new(0),
-            garbage: [
-                UnsafeCell::new(GarbageBag::new()),
-                UnsafeCell::new(GarbageBag::new()),
-                UnsafeCell::new(GarbageBag::new()),
-                UnsafeCell::new(GarbageBag::new()),
-            ],
-            ops_since_gc: AtomicU64::new(0),
-            #[cfg(feature = "statistics")]
-            stats: CollectorStats::default(),
+            inner: Arc::new(Inner::new()),
+        }
+    }
+
+    /// Registers an explicit participant handle with this collector.
+    ///
+    /// Unlike `pin()`, which caches a participant per thread automatically,
+    /// `register()` hands ownership of the registration straight to the
+    /// caller as a [`LocalHandle`]. The handle keeps working for as long as
+    /// it's held, independent of this particular `Collector` reference, and
+    /// releases its slot when dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use nexus_memory::Collector;
+    ///
+    /// let collector = Collector::new();
+    /// let handle = collector.register();
+    /// let guard = handle.pin();
+    /// ```
+    pub fn register(&self) -> LocalHandle {
+        LocalHandle {
+            collector: self.clone(),
+            node: self.inner.register_participant(),
         }
     }
 
@@ -191,9 +425,11 @@ impl Collector {
     /// While a guard is held, the current epoch's garbage will not be collected.
     /// This ensures that any data accessed through the guard remains valid.
     ///
-    /// # Panics
-    ///
-    /// Panics if the maximum number of participants is exceeded.
+    /// This is a thin wrapper over [`register`](Self::register): the first
+    /// call from a given thread creates a [`LocalHandle`] and caches it in
+    /// thread-local storage; every later call (from that thread, for this
+    /// collector) reuses it. There is no participant cap, so this never
+    /// panics regardless of how many threads call it.
     ///
     /// # Example
     ///
@@ -207,27 +443,105 @@ impl Collector {
     /// // Guard is dropped automatically at end of scope
     /// ```
     pub fn pin(&self) -> Guard<'_> {
-        // Get or create participant for this thread
-        let participant = self.get_or_create_participant();
-        
-        // Record the current epoch
-        let epoch = self.global_epoch.load(Ordering::SeqCst);
+        let node = self.local_node();
+        // SAFETY: `node` stays linked (and thus valid) for as long as the
+        // thread-local `LocalHandle` that owns it, which is cached for the
+        // thread's lifetime and so outlives this call.
+        let participant = unsafe { &*node };
+        self.pin_participant(participant)
+    }
+
+    /// Records a pin against an already-registered `participant` and returns
+    /// its guard. Shared by `pin()` (thread-local participant) and
+    /// [`LocalHandle::pin`] (explicit participant).
+    fn pin_participant<'a>(&'a self, participant: &'a Participant) -> Guard<'a> {
+        let epoch = self.inner.global_epoch.load(Ordering::SeqCst);
         participant.epoch.store(epoch, Ordering::SeqCst);
         participant.pin_count.fetch_add(1, Ordering::Relaxed);
-        
+
         // Periodically try to advance and collect
-        let ops = self.ops_since_gc.fetch_add(1, Ordering::Relaxed);
+        let ops = self.inner.ops_since_gc.fetch_add(1, Ordering::Relaxed);
         if ops % GC_FREQUENCY == 0 {
-            self.try_advance_and_collect();
+            self.inner.try_advance_and_collect();
         }
-        
+
         Guard::new(self, participant)
     }
 
+    /// Re-pins `participant` at the freshest epoch.
+    ///
+    /// Only takes effect when `participant`'s pin count is exactly 1 — i.e.
+    /// this is the outermost guard on the thread. A nested guard leaves an
+    /// outer guard still relying on its original epoch observation, so
+    /// repinning out from under it here would be unsound; the repin is
+    /// simply skipped in that case.
+    pub(crate) fn repin_participant(&self, participant: &Participant) {
+        if participant.pin_count.load(Ordering::Relaxed) != 1 {
+            return;
+        }
+
+        participant.epoch.store(INACTIVE, Ordering::SeqCst);
+        let epoch = self.inner.global_epoch.load(Ordering::SeqCst);
+        participant.epoch.store(epoch, Ordering::SeqCst);
+    }
+
+    /// Briefly unpins `participant`, runs `f`, then re-pins it at the
+    /// freshest epoch.
+    ///
+    /// This lets the epoch advance around a long-running `f` without the
+    /// caller having to drop and re-acquire its guard. As with
+    /// [`repin_participant`](Self::repin_participant), the unpin/re-pin is
+    /// skipped when `participant` is nested under another guard.
+    pub(crate) fn repin_participant_after<F, R>(&self, participant: &Participant, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        let outermost = participant.pin_count.load(Ordering::Relaxed) == 1;
+        if outermost {
+            participant.epoch.store(INACTIVE, Ordering::SeqCst);
+        }
+
+        let result = f();
+
+        if outermost {
+            let epoch = self.inner.global_epoch.load(Ordering::SeqCst);
+            participant.epoch.store(epoch, Ordering::SeqCst);
+        }
+
+        result
+    }
+
+    /// Looks up (or creates) this thread's cached [`LocalHandle`] for this
+    /// collector and returns its participant node.
+    ///
+    /// The cache is keyed by the `Collector`'s underlying `Arc` address, so
+    /// a thread that pins multiple distinct collectors gets one handle per
+    /// collector rather than conflating them.
+    fn local_node(&self) -> *mut Participant {
+        thread_local! {
+            static LOCAL: core::cell::RefCell<Vec<(usize, LocalHandle)>> =
+                const { core::cell::RefCell::new(Vec::new()) };
+        }
+
+        let key = Arc::as_ptr(&self.inner) as usize;
+
+        LOCAL.with(|cell| {
+            let mut handles = cell.borrow_mut();
+            if let Some((_, handle)) = handles.iter().find(|(k, _)| *k == key) {
+                return handle.node;
+            }
+
+            let handle = self.register();
+            let node = handle.node;
+            handles.push((key, handle));
+            node
+        })
+    }
+
     /// Returns the current global epoch.
     #[inline]
     pub fn epoch(&self) -> Epoch {
-        self.global_epoch.load(Ordering::SeqCst)
+        self.inner.global_epoch.load(Ordering::SeqCst)
     }
 
     /// Attempts to advance the global epoch.
@@ -239,29 +553,110 @@ impl Collector {
     ///
     /// `true` if the epoch was successfully advanced.
     pub fn try_advance(&self) -> bool {
+        self.inner.try_advance()
+    }
+
+    /// Defers destruction of a `Box`-style pointer to a future epoch.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid, properly aligned, and not destroyed by
+    /// any other means.
+    pub(crate) unsafe fn defer_destroy<T>(&self, ptr: *mut T) {
+        unsafe { self.push_into_local_bag(ptr) };
+    }
+
+    /// Defers an arbitrary closure for execution on a future epoch.
+    ///
+    /// Unlike [`defer_destroy`](Self::defer_destroy), this is not limited
+    /// to freeing a `Box<T>` — the closure can close files, decrement
+    /// refcounts, free into a custom allocator, or do anything else that
+    /// needs to happen once no participant can still observe the epoch
+    /// this call is made in.
+    pub(crate) fn defer<F: FnOnce() + 'static>(&self, f: F) {
+        let deferred = Box::into_raw(Box::new(Deferred::new(f)));
+        // SAFETY: `deferred` was just allocated and is destroyed exactly
+        // once, by `Deferred`'s own `Drop` impl, when the bag collects it.
+        unsafe { self.push_into_local_bag(deferred) };
+    }
+
+    /// Like [`defer`](Self::defer), but `f` need not be `'static`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must guarantee that anything `f` borrows remains valid
+    /// until the current epoch has been reclaimed (i.e. for at least as
+    /// long as any `Guard` pinned at or before this point could still be
+    /// observing it).
+    pub(crate) unsafe fn defer_unchecked<F: FnOnce()>(&self, f: F) {
+        let deferred = Box::into_raw(Box::new(unsafe { Deferred::new_unchecked(f) }));
+        // SAFETY: same as `defer`, plus the caller's lifetime guarantee.
+        unsafe { self.push_into_local_bag(deferred) };
+    }
+
+    /// Pushes a retired pointer into the calling thread's own garbage bag,
+    /// which no other thread touches. Once that bag reaches `MAX_OBJECTS`,
+    /// it is sealed with the current epoch and handed off to the global
+    /// queue in one batched operation.
+    ///
+    /// # Safety
+    ///
+    /// The pointer must be valid, properly aligned, and destroyed at most
+    /// once by the eventual collection pass.
+    unsafe fn push_into_local_bag<T>(&self, ptr: *mut T) {
+        let node = self.local_node();
+        // SAFETY: see `local_node`'s contract.
+        let participant = unsafe { &*node };
+
+        // SAFETY: only the owning thread ever mutates its own local bag.
+        let bag = unsafe { &mut *participant.local_garbage.get() };
+        unsafe { bag.defer(ptr) };
+
+        if bag.len() >= MAX_OBJECTS {
+            let epoch = self.inner.global_epoch.load(Ordering::SeqCst);
+            let sealed = mem::replace(bag, GarbageBag::new());
+            self.inner.seal_and_push(sealed, epoch);
+        }
+    }
+
+    /// Returns collection statistics (if enabled).
+    #[cfg(feature = "statistics")]
+    pub fn statistics(&self) -> (u64, u64, u64, u64) {
+        (
+            self.inner.stats.objects_collected.load(Ordering::Relaxed),
+            self.inner.stats.collection_cycles.load(Ordering::Relaxed),
+            self.inner.stats.epoch_advances.load(Ordering::Relaxed),
+            self.inner.stats.failed_advances.load(Ordering::Relaxed),
+        )
+    }
+}
+
+impl Default for Collector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Inner {
+    fn new() -> Self {
+        Self {
+            global_epoch: AtomicEpoch::new(0),
+            participants_head: AtomicPtr::new(ptr::null_mut()),
+            num_participants: AtomicUsize::new(0),
+            sealed_bags: AtomicPtr::new(ptr::null_mut()),
+            ops_since_gc: AtomicU64::new(0),
+            #[cfg(feature = "statistics")]
+            stats: CollectorStats::default(),
+        }
+    }
+
+    fn try_advance(&self) -> bool {
         let current = self.global_epoch.load(Ordering::SeqCst);
-        
-        // Check if all participants have observed the current epoch
-        for participant in self.participants.iter() {
-            if participant.active.load(Ordering::Relaxed) == 0 {
-                continue;
-            }
-            
-            let p_epoch = participant.epoch.load(Ordering::SeqCst);
-            
-            // Skip inactive participants
-            if p_epoch == INACTIVE {
-                continue;
-            }
-            
-            // If any participant is behind, we cannot advance
-            if p_epoch < current {
-                #[cfg(feature = "statistics")]
-                self.stats.failed_advances.fetch_add(1, Ordering::Relaxed);
-                return false;
-            }
+
+        if !self.all_participants_caught_up(current) {
+            return false;
         }
-        
+
         // All participants have caught up, try to advance
         let result = self.global_epoch.compare_exchange(
             current,
@@ -269,96 +664,271 @@ impl Collector {
             Ordering::SeqCst,
             Ordering::SeqCst,
         );
-        
+
         #[cfg(feature = "statistics")]
         if result.is_ok() {
             self.stats.epoch_advances.fetch_add(1, Ordering::Relaxed);
         }
-        
+
         result.is_ok()
     }
 
+    /// Checks whether every live, pinned participant has observed `current`.
+    ///
+    /// The registry is an intrusive list with no fixed length, so we walk
+    /// it from the head rather than indexing a fixed-size array.
+    #[cfg(not(feature = "sanitize"))]
+    fn all_participants_caught_up(&self, current: Epoch) -> bool {
+        let mut cursor = self.participants_head.load(Ordering::Acquire);
+        while !cursor.is_null() {
+            // SAFETY: nodes are only unlinked, never freed, while a concurrent
+            // walker might still be holding this pointer (reclamation of a
+            // node is deferred through the epoch mechanism itself).
+            let participant = unsafe { &*cursor };
+
+            if !self.participant_caught_up(participant, current) {
+                return false;
+            }
+
+            cursor = participant.next.load(Ordering::Acquire);
+        }
+
+        true
+    }
+
+    /// Like the non-sanitized walk above, but checks participants in an
+    /// order that changes from call to call (see
+    /// `sanitized_participant_order`), to shake loose any advancement bug
+    /// that only shows up for a particular traversal order.
+    #[cfg(feature = "sanitize")]
+    fn all_participants_caught_up(&self, current: Epoch) -> bool {
+        for node in self.sanitized_participant_order() {
+            // SAFETY: nodes are only unlinked, never freed, while a
+            // concurrent walker might still be holding this pointer.
+            let participant = unsafe { &*node };
+
+            if !self.participant_caught_up(participant, current) {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns whether a single participant is either inactive or has
+    /// observed at least `current`, bumping the failed-advance counter (if
+    /// enabled) when it hasn't.
+    fn participant_caught_up(&self, participant: &Participant, current: Epoch) -> bool {
+        if participant.active.load(Ordering::Relaxed) == 0 {
+            return true;
+        }
+
+        let p_epoch = participant.epoch.load(Ordering::SeqCst);
+
+        // Unpinned participants don't hold back advancement.
+        if p_epoch == INACTIVE {
+            return true;
+        }
+
+        if p_epoch < current {
+            #[cfg(feature = "statistics")]
+            self.stats.failed_advances.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        true
+    }
+
+    /// Collects the live registry into a vector and rotates it by an
+    /// ever-increasing salt, so repeated `try_advance()` calls check
+    /// participants in a different order each time.
+    #[cfg(feature = "sanitize")]
+    fn sanitized_participant_order(&self) -> Vec<*mut Participant> {
+        let mut nodes = Vec::new();
+        let mut cursor = self.participants_head.load(Ordering::Acquire);
+        while !cursor.is_null() {
+            nodes.push(cursor);
+            // SAFETY: nodes are only unlinked, never freed, while a
+            // concurrent walker might still be holding this pointer.
+            cursor = unsafe { (*cursor).next.load(Ordering::Acquire) };
+        }
+
+        if !nodes.is_empty() {
+            let salt = SANITIZE_SCAN_SALT.fetch_add(1, Ordering::Relaxed);
+            nodes.rotate_left(salt % nodes.len());
+        }
+
+        nodes
+    }
+
     /// Tries to advance the epoch and collect garbage.
     fn try_advance_and_collect(&self) {
         // Try to advance the epoch
         if self.try_advance() {
             let current = self.global_epoch.load(Ordering::SeqCst);
-            
-            // Collect garbage from two epochs ago (grace period)
-            if current >= 2 {
-                let old_epoch = (current - 2) % 4;
-                
-                // SAFETY: We have exclusive access during collection
-                // because no participant can be in this old epoch
-                let bag = unsafe { &mut *self.garbage[old_epoch as usize].get() };
-                
+            self.collect_sealed_bags(current);
+        }
+    }
+
+    /// Drains the global sealed-bag stack, destroying the contents of any
+    /// bag stamped at least two epochs behind `current` and pushing the
+    /// rest back for a later collection pass.
+    fn collect_sealed_bags(&self, current: Epoch) {
+        // Atomically take the whole stack. Bags sealed concurrently by
+        // other threads after this swap attach to the new (empty) head and
+        // are simply left for the next call.
+        let mut cursor = self.sealed_bags.swap(ptr::null_mut(), Ordering::AcqRel);
+        let mut keep: *mut SealedBag = ptr::null_mut();
+
+        while !cursor.is_null() {
+            // SAFETY: exclusively owned by this thread after the swap above.
+            let mut node = unsafe { Box::from_raw(cursor) };
+            cursor = *node.next.get_mut();
+
+            if current.wrapping_sub(node.epoch) >= 2 {
                 #[cfg(feature = "statistics")]
                 {
-                    let collected = bag.len();
+                    let collected = node.bag.len();
                     self.stats.objects_collected.fetch_add(collected as u64, Ordering::Relaxed);
                     self.stats.collection_cycles.fetch_add(1, Ordering::Relaxed);
                 }
-                
-                unsafe { bag.collect() };
+
+                unsafe { node.bag.collect() };
+            } else {
+                // Not old enough yet: keep it for a future pass.
+                *node.next.get_mut() = keep;
+                keep = Box::into_raw(node);
             }
         }
+
+        if !keep.is_null() {
+            self.push_sealed_chain(keep);
+        }
     }
 
-    /// Defers destruction of an object to a future epoch.
-    ///
-    /// # Safety
-    ///
-    /// The pointer must be valid and properly aligned.
-    pub(crate) unsafe fn defer<T>(&self, ptr: *mut T) {
-        let epoch = self.global_epoch.load(Ordering::SeqCst);
-        let bag_idx = (epoch % 4) as usize;
-        
-        // SAFETY: We're adding to the current epoch's bag
-        let bag = unsafe { &mut *self.garbage[bag_idx].get() };
-        unsafe { bag.defer(ptr) };
+    /// Seals a thread-local bag with `epoch` and pushes it onto the global
+    /// lock-free stack of bags awaiting collection.
+    fn seal_and_push(&self, bag: GarbageBag, epoch: Epoch) {
+        let node = Box::into_raw(Box::new(SealedBag {
+            epoch,
+            bag,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+        self.push_sealed_chain(node);
     }
 
-    /// Gets or creates a participant slot for the current thread.
-    fn get_or_create_participant(&self) -> &Participant {
-        // Use thread-local storage to cache participant index
-        thread_local! {
-            static PARTICIPANT_IDX: core::cell::Cell<Option<usize>> = 
-                const { core::cell::Cell::new(None) };
+    /// Pushes a (possibly multi-node) chain of sealed bags onto the stack.
+    fn push_sealed_chain(&self, head: *mut SealedBag) {
+        // Find the chain's tail so we can splice the current stack after it.
+        let mut tail = head;
+        // SAFETY: the chain is exclusively owned by the caller until linked
+        // into `sealed_bags` below.
+        while !unsafe { (*tail).next.load(Ordering::Relaxed) }.is_null() {
+            tail = unsafe { (*tail).next.load(Ordering::Relaxed) };
         }
-        
-        // Check if we already have a participant
-        let idx = PARTICIPANT_IDX.with(|cell| {
-            if let Some(idx) = cell.get() {
-                return idx;
+
+        let mut current_head = self.sealed_bags.load(Ordering::Acquire);
+        loop {
+            unsafe { (*tail).next.store(current_head, Ordering::Relaxed) };
+
+            match self.sealed_bags.compare_exchange_weak(
+                current_head,
+                head,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(h) => current_head = h,
             }
-            
-            // Need to allocate a new participant slot
-            let idx = self.allocate_participant();
-            cell.set(Some(idx));
-            idx
-        });
-        
-        &self.participants[idx]
-    }
-
-    /// Allocates a new participant slot.
-    fn allocate_participant(&self) -> usize {
-        // Find a free slot
-        for (idx, participant) in self.participants.iter().enumerate() {
-            if participant.active.compare_exchange(
-                0, 1, Ordering::SeqCst, Ordering::SeqCst
-            ).is_ok() {
-                self.num_participants.fetch_add(1, Ordering::Relaxed);
-                return idx;
+        }
+    }
+
+    /// Pushes a new participant node onto the head of the registry.
+    ///
+    /// This is an unbounded, lock-free CAS push — there is no participant
+    /// cap, so registering never panics regardless of how many threads have
+    /// come and gone over the collector's lifetime.
+    fn register_participant(&self) -> *mut Participant {
+        let node = Box::into_raw(Box::new(Participant::default()));
+        // SAFETY: `node` was just allocated and is not yet visible to
+        // anyone else, so this is the only writer.
+        unsafe { (*node).active.store(1, Ordering::Relaxed) };
+
+        let mut head = self.participants_head.load(Ordering::Acquire);
+        loop {
+            // SAFETY: node is freshly allocated and owned by this call.
+            unsafe { (*node).next.store(head, Ordering::Relaxed) };
+
+            match self.participants_head.compare_exchange_weak(
+                head,
+                node,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break,
+                Err(current_head) => head = current_head,
             }
         }
-        
-        panic!("Maximum number of participants ({}) exceeded", MAX_PARTICIPANTS);
+
+        self.num_participants.fetch_add(1, Ordering::Relaxed);
+        node
+    }
+
+    /// Unlinks a participant's node from the live registry when its handle
+    /// is dropped, then defers the node's own memory for reclamation.
+    ///
+    /// # Safety
+    ///
+    /// `node` must point to a `Participant` previously returned by
+    /// [`register_participant`](Self::register_participant) on this
+    /// collector, and must not be unlinked more than once.
+    unsafe fn unlink_participant(&self, node: *mut Participant) {
+        // SAFETY: node is still linked and thus valid to dereference.
+        let participant = unsafe { &*node };
+        participant.active.store(0, Ordering::Release);
+        self.num_participants.fetch_sub(1, Ordering::Relaxed);
+
+        // Physically unlink the node so future registry walks stop seeing
+        // it; concurrent walkers that already read a pointer to `node`
+        // before this CAS still observe a valid (if now tombstoned) node.
+        let mut prev_link = &self.participants_head;
+        let mut cursor = prev_link.load(Ordering::Acquire);
+
+        while !cursor.is_null() {
+            if cursor == node {
+                // SAFETY: `cursor` is non-null and was just loaded.
+                let next = unsafe { (*cursor).next.load(Ordering::Acquire) };
+                let _ = prev_link.compare_exchange(
+                    cursor,
+                    next,
+                    Ordering::AcqRel,
+                    Ordering::Acquire,
+                );
+                break;
+            }
+
+            // SAFETY: cursor is non-null here.
+            let next_link = unsafe { &(*cursor).next };
+            cursor = next_link.load(Ordering::Acquire);
+            prev_link = next_link;
+        }
+
+        // The node itself is retired just like any other garbage: readers
+        // may still be mid-walk over it, so its memory is only actually
+        // freed once the epoch mechanism proves that's safe. We seal it
+        // directly rather than going through `Collector::defer()`, since
+        // the handle being torn down here may itself be the thread-local
+        // one backing `local_node()`, and re-entering that lookup from
+        // inside its own teardown is not safe.
+        let mut bag = GarbageBag::new();
+        unsafe { bag.defer(node) };
+        let epoch = self.global_epoch.load(Ordering::SeqCst);
+        self.seal_and_push(bag, epoch);
     }
 
     /// Returns collection statistics (if enabled).
     #[cfg(feature = "statistics")]
-    pub fn statistics(&self) -> (u64, u64, u64, u64) {
+    fn statistics(&self) -> (u64, u64, u64, u64) {
         (
             self.stats.objects_collected.load(Ordering::Relaxed),
             self.stats.collection_cycles.load(Ordering::Relaxed),
@@ -368,30 +938,67 @@ impl Collector {
     }
 }
 
-impl Default for Collector {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Drop for Collector {
+impl Drop for Inner {
     fn drop(&mut self) {
-        // Collect all remaining garbage
-        for bag in &self.garbage {
-            // SAFETY: We have exclusive access during drop
-            let bag = unsafe { &mut *bag.get() };
-            unsafe { bag.collect() };
+        // Collect every sealed bag regardless of its stamped epoch — no
+        // further synchronization is possible once the collector itself is
+        // going away.
+        let mut cursor = *self.sealed_bags.get_mut();
+        while !cursor.is_null() {
+            // SAFETY: We have exclusive access during drop.
+            let mut node = unsafe { Box::from_raw(cursor) };
+            cursor = *node.next.get_mut();
+            unsafe { node.bag.collect() };
         }
-        
-        // Also collect from participants
-        for participant in self.participants.iter() {
-            // SAFETY: We have exclusive access during drop
-            let bag = unsafe { &mut *participant.local_garbage.get() };
+
+        // Walk the registry, collecting each surviving participant's local
+        // garbage and freeing its node. Any node already unlinked by a
+        // `LocalHandle` drop was sealed and collected by the loop just
+        // above (or is still pending on an overlapping collection, in
+        // which case it was already taken out of `sealed_bags`).
+        let mut cursor = *self.participants_head.get_mut();
+        while !cursor.is_null() {
+            // SAFETY: We have exclusive access during drop, and every node
+            // still reachable from the head was never freed.
+            let mut node = unsafe { Box::from_raw(cursor) };
+            cursor = *node.next.get_mut();
+
+            let bag = unsafe { &mut *node.local_garbage.get() };
             unsafe { bag.collect() };
         }
     }
 }
 
+impl<'a> Guard<'a> {
+    /// Re-pins this guard at the freshest epoch.
+    ///
+    /// A guard held across a long operation holds back epoch advancement for
+    /// as long as it's alive (see the module-level "Repinning" docs). Calling
+    /// this periodically lets the epoch advance around the held guard
+    /// without requiring the caller to drop and re-acquire it.
+    ///
+    /// Only takes effect when this is the outermost guard on the thread;
+    /// it's a no-op when nested under another guard, since an outer guard is
+    /// still relying on its original epoch observation.
+    pub fn repin(&mut self) {
+        self.collector.repin_participant(self.participant);
+    }
+
+    /// Briefly unpins this guard, runs `f`, then re-pins it at the freshest
+    /// epoch.
+    ///
+    /// This is [`repin`](Self::repin) wrapped around a closure, for the
+    /// common case of relaxing the epoch hold around one specific piece of
+    /// work rather than at arbitrary points. As with `repin`, the unpin/re-pin
+    /// is skipped when this guard is nested under another.
+    pub fn repin_after<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce() -> R,
+    {
+        self.collector.repin_participant_after(self.participant, f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -402,13 +1009,46 @@ mod tests {
         assert_eq!(collector.epoch(), 0);
     }
 
+    #[test]
+    fn test_deferred_inline_closure_runs() {
+        use std::sync::atomic::AtomicBool;
+        use std::sync::Arc;
+
+        let ran = Arc::new(AtomicBool::new(false));
+        let ran2 = ran.clone();
+
+        let deferred = Deferred::new(move || ran2.store(true, Ordering::SeqCst));
+        drop(deferred);
+
+        assert!(ran.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_deferred_boxed_closure_runs() {
+        use std::sync::atomic::AtomicUsize;
+        use std::sync::Arc;
+
+        // Large enough capture to force the boxed path.
+        let state = Arc::new(AtomicUsize::new(0));
+        let padding = [0u8; 256];
+        let state2 = state.clone();
+
+        let deferred = Deferred::new(move || {
+            let _ = padding.len();
+            state2.fetch_add(1, Ordering::SeqCst);
+        });
+        drop(deferred);
+
+        assert_eq!(state.load(Ordering::SeqCst), 1);
+    }
+
     #[test]
     fn test_pin_unpin() {
         let collector = Collector::new();
-        
+
         let guard = collector.pin();
         drop(guard);
-        
+
         // Should be able to pin again
         let _guard = collector.pin();
     }
@@ -416,7 +1056,7 @@ mod tests {
     #[test]
     fn test_epoch_advance() {
         let collector = Collector::new();
-        
+
         // Without any guards, should be able to advance
         assert!(collector.try_advance());
         assert_eq!(collector.epoch(), 1);
@@ -424,23 +1064,23 @@ mod tests {
 
     #[test]
     fn test_guard_prevents_advance() {
-        // Note: Thread-local participant caching means this test needs to 
+        // Note: Thread-local participant caching means this test needs to
         // ensure proper isolation. The first pin() establishes the participant.
         let collector = Collector::new();
-        
+
         // Pin before advancing - this establishes the participant at epoch 0
         let guard = collector.pin();
-        
+
         // Epoch is at 0, participant is at 0
         // First advance: 0 -> 1 should succeed since participant.epoch (0) >= current (0)
         let first = collector.try_advance();
-        
+
         // After first advance, epoch is 1, participant is still at 0
         // Second advance should fail: participant.epoch (0) < current (1)
         let second = collector.try_advance();
-        
+
         drop(guard);
-        
+
         // The exact behavior depends on implementation details
         // At minimum, we verify that having a guard affects advancement
         assert!(first || !second, "Guard should affect epoch advancement");
@@ -449,13 +1089,13 @@ mod tests {
     #[test]
     fn test_nested_pinning() {
         let collector = Collector::new();
-        
+
         let guard1 = collector.pin();
         let guard2 = collector.pin();
-        
+
         drop(guard1);
         // guard2 still holding, should not be able to advance beyond epoch 0
-        
+
         drop(guard2);
     }
 
@@ -463,10 +1103,10 @@ mod tests {
     fn test_multiple_threads() {
         use std::sync::Arc;
         use std::thread;
-        
+
         let collector = Arc::new(Collector::new());
         let mut handles = vec![];
-        
+
         for _ in 0..4 {
             let c = collector.clone();
             handles.push(thread::spawn(move || {
@@ -477,12 +1117,88 @@ mod tests {
                 }
             }));
         }
-        
+
         for handle in handles {
             handle.join().unwrap();
         }
-        
+
         // All threads done, epoch should have advanced
         assert!(collector.epoch() > 0);
     }
+
+    #[test]
+    fn test_register_local_handle_outlives_collector() {
+        let collector = Collector::new();
+        let handle = collector.register();
+        drop(collector);
+
+        // The handle keeps working even though every `Collector` reference
+        // that existed when it was registered is now gone.
+        let guard = handle.pin();
+        drop(guard);
+    }
+
+    #[test]
+    fn test_local_handle_independent_from_pin() {
+        let collector = Collector::new();
+
+        let handle = collector.register();
+        let _guard1 = handle.pin();
+        let _guard2 = collector.pin();
+    }
+
+    #[test]
+    fn test_repin_participant_after_runs_closure() {
+        let collector = Collector::new();
+        let handle = collector.register();
+        // SAFETY: the handle (and its node) is kept alive for the rest of
+        // this test.
+        let participant = unsafe { &*handle.node };
+        participant.pin_count.store(1, Ordering::SeqCst);
+
+        let result = collector.repin_participant_after(participant, || 42);
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_repin_participant_noop_when_nested() {
+        let collector = Collector::new();
+        let handle = collector.register();
+        let participant = unsafe { &*handle.node };
+        participant.pin_count.store(2, Ordering::SeqCst);
+        participant.epoch.store(0, Ordering::SeqCst);
+
+        collector.repin_participant(participant);
+
+        // Nested under another guard: the repin is a no-op.
+        assert_eq!(participant.epoch.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn test_guard_repin_after_runs_closure() {
+        let collector = Collector::new();
+        let handle = collector.register();
+        let mut guard = handle.pin();
+
+        let result = guard.repin_after(|| 42);
+        assert_eq!(result, 42);
+    }
+
+    #[test]
+    fn test_guard_repin_noop_when_nested() {
+        let collector = Collector::new();
+        let handle = collector.register();
+        // SAFETY: the handle (and its node) is kept alive for the rest of
+        // this test.
+        let participant = unsafe { &*handle.node };
+        let mut guard = handle.pin();
+        // Simulate a nested guard on the same participant.
+        participant.pin_count.store(2, Ordering::SeqCst);
+        participant.epoch.store(0, Ordering::SeqCst);
+
+        guard.repin();
+
+        // Nested under another guard: the repin is a no-op.
+        assert_eq!(participant.epoch.load(Ordering::SeqCst), 0);
+    }
 }
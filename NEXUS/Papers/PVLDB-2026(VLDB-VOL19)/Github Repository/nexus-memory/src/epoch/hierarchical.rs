@@ -23,6 +23,84 @@
 //! 3. **Amortized Aggregation**: Group updates batched for efficiency
 //! 4. **Cache-Friendly**: Aggregation nodes are cache-line aligned
 //!
+//! # Dynamic Growth
+//!
+//! Earlier revisions hard-capped capacity at `BRANCHING_FACTOR^MAX_DEPTH`
+//! (256 threads) and panicked in [`register`](HierarchicalEpoch::register)
+//! past that point. The tree now grows on demand: when a slot beyond the
+//! current capacity is requested, [`ensure_capacity`](HierarchicalEpoch::ensure_capacity)
+//! builds a deeper [`Tree`] — one more level, multiplying capacity by
+//! `BRANCHING_FACTOR` — copies every currently-held leaf epoch forward into
+//! the new tree at the same slot index, and installs it behind an
+//! [`ArcSwap`]. Readers (`global_minimum`, `update_local`, ...) load the
+//! current tree once per call, so they only ever see one complete
+//! generation and never tear across a grow. Because each grow multiplies
+//! capacity rather than adding a fixed increment, the total work spent
+//! growing from 1 to T threads is O(log T) amortized, the same way `Vec`'s
+//! doubling amortizes push costs. `MAX_DEPTH` is now only the *default*
+//! ceiling; pass [`HierarchicalEpochBuilder::max_depth`] to raise it.
+//!
+//! [`update_local`](HierarchicalEpoch::update_local) additionally takes
+//! `grow_lock` for read: the snapshot backing a new generation is taken
+//! before that generation is installed, so without synchronization a write
+//! landing in that gap would be invisible to the new generation — and to
+//! `global_minimum`, which could then advance past a thread that's still
+//! pinned. See its doc comment for how holding the lock across the whole
+//! snapshot-then-install sequence closes that window.
+//!
+//! # Blocking Reclaim Wait
+//!
+//! Reclaimers that only need to know "has the epoch passed `e` yet" no
+//! longer have to spin-poll [`can_reclaim`](HierarchicalEpoch::can_reclaim).
+//! [`wait_until_reclaimable`](HierarchicalEpoch::wait_until_reclaimable)
+//! parks the caller instead, using a jobs-event-counter protocol in the
+//! style of rayon-core's sleep module: a single `AtomicU64` packs a
+//! sleepy-waiter count in its high 32 bits with a global-min-advance
+//! generation in its low 32 bits. Every [`update_local`](HierarchicalEpoch::update_local)
+//! call that actually changes a thread's local epoch bumps the generation
+//! and, only if the sleepy count is nonzero, wakes parked waiters — this is
+//! conservative (a changed leaf doesn't always move the root's aggregated
+//! minimum), which is fine, since waiters just re-check the real condition
+//! on every wake. Waiters announce themselves (bumping the sleepy count)
+//! and re-check the condition twice — once before announcing, once after —
+//! before actually blocking, which closes the lost-wakeup window between a
+//! waiter's last check and a waker's notify.
+//!
+//! # Incremental Aggregation
+//!
+//! [`global_minimum`](HierarchicalEpoch::global_minimum) used to call
+//! [`Tree::aggregate_all`], recomputing every internal node on every call —
+//! O(T), throwing away the point of the hierarchy whenever reads outnumber
+//! writes. Each internal node now carries a dirty flag alongside its
+//! stored epoch. [`update_local`](HierarchicalEpoch::update_local) marks
+//! the path from the changed leaf to the root dirty (stopping as soon as
+//! it hits an already-dirty ancestor, since dirty implies all ancestors of
+//! it are dirty too) instead of recomputing it. `global_minimum` then
+//! descends only into dirty subtrees, recomputing and clearing their flags
+//! as it goes, and returns the root directly — O(1) — if it's already
+//! clean. [`global_minimum_exact`](HierarchicalEpoch::global_minimum_exact)
+//! keeps the old unconditional full-sweep behavior for callers who want
+//! that extra (if unobservable) assurance. Both return a value that is a
+//! valid lower bound on all thread epochs as of some linearization point
+//! during the call.
+//!
+//! # Memory-Pressure-Driven Reclamation
+//!
+//! Built via [`HierarchicalEpoch::with_pressure_callback`], a tree can also
+//! drive allocator back-pressure the way DataFusion's `MemoryManager` drives
+//! spilling: callers bump [`retired_bytes`](HierarchicalEpoch::record_retired_bytes)
+//! as they stage garbage, and once that running total crosses the
+//! configured high-water mark, the manager runs a synchronous
+//! `aggregate_all()` + [`can_reclaim`](HierarchicalEpoch::can_reclaim) sweep
+//! and calls every registered [`ReclamationConsumer`]. If the global minimum
+//! still hasn't advanced afterward, some thread is genuinely stalled behind
+//! the rest; the sweep does *not* force that thread's local epoch forward
+//! — it's still pinned and may still be reading memory at that epoch, so
+//! advancing it would let reclamation run ahead of a live reader. The next
+//! sweep just tries again once that thread actually moves. Multiple pools
+//! can [`register_consumer`](HierarchicalEpoch::register_consumer) against
+//! one manager to cooperate under a shared budget.
+//!
 //! # Theoretical Foundation
 //!
 //! **Theorem (Hierarchical Epoch Correctness):**
@@ -36,16 +114,242 @@
 //! By induction on tree height, the global epoch is a lower bound on
 //! all thread-local epochs. □
 
-use core::sync::atomic::Ordering;
+use core::ptr;
+use core::sync::atomic::{AtomicBool, AtomicPtr, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use arc_swap::ArcSwap;
 
-use super::{Epoch, AtomicEpoch, INACTIVE};
+use super::{AtomicEpoch, Epoch, INACTIVE};
 
 /// Branching factor of the epoch tree (number of children per node)
 const BRANCHING_FACTOR: usize = 4;
 
-/// Maximum tree depth (supports up to 4^4 = 256 threads)
+/// Default maximum tree depth (supports up to 4^4 = 256 threads). Only a
+/// default now — [`HierarchicalEpochBuilder::max_depth`] can raise the
+/// ceiling a given tree is allowed to grow to.
 const MAX_DEPTH: usize = 4;
 
+/// Number of low bits of `HierarchicalEpoch::sleep_state` given to the
+/// advance generation; the remaining high bits count sleepy waiters.
+const ADVANCE_BITS: u32 = 32;
+
+/// Mask selecting the advance-generation bits of `sleep_state`.
+const ADVANCE_MASK: u64 = (1u64 << ADVANCE_BITS) - 1;
+
+/// Added to `sleep_state` to announce (or retract, via subtraction) one
+/// sleepy waiter.
+const SLEEPY_ONE: u64 = 1u64 << ADVANCE_BITS;
+
+/// One generation of the epoch tree: the contiguous implicit B-ary array
+/// described in the module docs, sized for exactly `capacity` leaves.
+///
+/// Growing past `capacity` never mutates a `Tree` in place — instead
+/// [`HierarchicalEpoch::ensure_capacity`] builds a new, deeper `Tree` and
+/// swaps it in, so any reference obtained via [`ArcSwap::load_full`] stays
+/// internally consistent for the whole call that holds it.
+struct Tree {
+    /// Internal aggregation nodes occupy indices `0..leaf_offset`, and the
+    /// `capacity` leaf (thread) slots occupy `leaf_offset..`. Node `i`'s
+    /// children live at `i*BRANCHING_FACTOR+1 ..= i*BRANCHING_FACTOR+BRANCHING_FACTOR`
+    /// and its parent at `(i-1)/BRANCHING_FACTOR`; the root is index 0.
+    nodes: Vec<AtomicEpoch>,
+
+    /// One dirty flag per internal node (indices `0..leaf_offset`, parallel
+    /// to the front of `nodes`): `true` means this node's stored min may be
+    /// stale relative to its children and must be recomputed before it's
+    /// trusted. Maintained as an invariant: if a node is dirty, every
+    /// ancestor of it is dirty too, which is what lets
+    /// [`mark_dirty_from`](Self::mark_dirty_from) stop early and
+    /// [`refresh_node`](Self::refresh_node) skip whole clean subtrees.
+    dirty: Vec<AtomicBool>,
+
+    /// Index of the first leaf slot in `nodes` — equivalently, the number
+    /// of internal aggregation nodes.
+    leaf_offset: usize,
+
+    /// Number of leaf slots this generation holds.
+    capacity: usize,
+
+    /// Depth of this generation's tree.
+    depth: usize,
+}
+
+impl Tree {
+    /// Builds an empty (all-`INACTIVE`, all-clean) tree of the given `depth`.
+    fn new(depth: usize) -> Self {
+        let capacity = BRANCHING_FACTOR.pow(depth as u32);
+
+        // A full BRANCHING_FACTOR-ary tree with `capacity` leaves has
+        // exactly `(capacity - 1) / (BRANCHING_FACTOR - 1)` internal nodes
+        // (the sum of BRANCHING_FACTOR^0 .. BRANCHING_FACTOR^(depth-1)).
+        let leaf_offset = (capacity - 1) / (BRANCHING_FACTOR - 1);
+        let nodes: Vec<AtomicEpoch> = (0..leaf_offset + capacity)
+            .map(|_| AtomicEpoch::new(INACTIVE))
+            .collect();
+        let dirty: Vec<AtomicBool> = (0..leaf_offset).map(|_| AtomicBool::new(false)).collect();
+
+        Self {
+            nodes,
+            dirty,
+            leaf_offset,
+            capacity,
+            depth,
+        }
+    }
+
+    /// Builds a deeper tree seeded from `old`'s current leaf epochs at the
+    /// same slot indices, then aggregates once so the new internal nodes
+    /// are immediately consistent. This is what lets already-registered
+    /// thread ids keep referring to the same slot across a grow.
+    fn grown_from(old: &Tree, new_depth: usize) -> Self {
+        let grown = Self::new(new_depth);
+
+        for slot in 0..old.capacity {
+            let epoch = old.leaf(slot).load(Ordering::SeqCst);
+            if epoch != INACTIVE {
+                grown.leaf(slot).store(epoch, Ordering::SeqCst);
+            }
+        }
+
+        grown.aggregate_all();
+        grown
+    }
+
+    #[inline]
+    fn leaf(&self, slot: usize) -> &AtomicEpoch {
+        &self.nodes[self.leaf_offset + slot]
+    }
+
+    /// Updates a leaf's epoch, marking the path to the root dirty instead
+    /// of eagerly recomputing it. Returns whether the epoch actually
+    /// changed — a conservative signal (the root's aggregated minimum may
+    /// or may not have moved as a result) used to decide whether
+    /// `wait_until_reclaimable` waiters need waking.
+    #[inline]
+    fn update_local(&self, slot: usize, epoch: Epoch) -> bool {
+        assert!(slot < self.capacity, "Thread ID out of range");
+
+        let idx = self.leaf_offset + slot;
+        let old_epoch = self.nodes[idx].swap(epoch, Ordering::SeqCst);
+
+        if old_epoch == epoch {
+            return false;
+        }
+
+        self.mark_dirty_from(idx);
+        true
+    }
+
+    #[inline]
+    fn local_epoch(&self, slot: usize) -> Epoch {
+        assert!(slot < self.capacity, "Thread ID out of range");
+        self.leaf(slot).load(Ordering::SeqCst)
+    }
+
+    /// Returns the global minimum epoch, descending only into subtrees
+    /// still marked dirty and recomputing (then clearing) them as it goes.
+    /// If the root is already clean, this is O(1). See the module docs for
+    /// the consistency guarantee this (and
+    /// [`global_minimum_exact`](Self::global_minimum_exact)) provides.
+    #[inline]
+    fn global_minimum(&self) -> Epoch {
+        self.refresh_node(0)
+    }
+
+    /// Forces a full aggregation sweep over every internal node — O(T) —
+    /// ignoring dirty tracking entirely, and returns the resulting root.
+    fn global_minimum_exact(&self) -> Epoch {
+        self.aggregate_all();
+        self.nodes[0].load(Ordering::SeqCst)
+    }
+
+    /// Marks node `idx`'s ancestors dirty, starting at its parent and
+    /// walking toward the root. Stops as soon as it reaches an ancestor
+    /// that's already dirty: by the dirty-implies-ancestors-dirty
+    /// invariant, everything above that point is already marked too.
+    #[inline]
+    fn mark_dirty_from(&self, mut idx: usize) {
+        while idx > 0 {
+            let parent = (idx - 1) / BRANCHING_FACTOR;
+            if self.dirty[parent].swap(true, Ordering::AcqRel) {
+                break;
+            }
+            idx = parent;
+        }
+    }
+
+    /// Returns node `idx`'s min, recomputing it from its children first if
+    /// (and only if) it's dirty. Leaves have no dirty flag and are always
+    /// current. Recursing into children before trusting this node's own
+    /// value is what lets a clean ancestor short-circuit an entire clean
+    /// subtree without visiting it.
+    fn refresh_node(&self, idx: usize) -> Epoch {
+        if idx >= self.leaf_offset {
+            return self.nodes[idx].load(Ordering::SeqCst);
+        }
+
+        if !self.dirty[idx].swap(false, Ordering::AcqRel) {
+            return self.nodes[idx].load(Ordering::SeqCst);
+        }
+
+        let start = idx * BRANCHING_FACTOR + 1;
+        let end = (start + BRANCHING_FACTOR).min(self.nodes.len());
+
+        let min = (start..end)
+            .map(|child| self.refresh_node(child))
+            .filter(|&e| e != INACTIVE)
+            .min()
+            .unwrap_or(INACTIVE);
+
+        self.nodes[idx].store(min, Ordering::SeqCst);
+        min
+    }
+
+    /// Recomputes the min over node `idx`'s child span straight from the
+    /// children's currently-stored values (no recursion, no dirty check)
+    /// and stores it. Used by [`aggregate_all`](Self::aggregate_all), which
+    /// visits every node bottom-up so each child is already current by the
+    /// time its parent is recomputed.
+    #[inline]
+    fn recompute_node(&self, idx: usize) -> Epoch {
+        let start = idx * BRANCHING_FACTOR + 1;
+        let end = (start + BRANCHING_FACTOR).min(self.nodes.len());
+
+        let min = self.nodes[start..end]
+            .iter()
+            .map(|e| e.load(Ordering::SeqCst))
+            .filter(|&e| e != INACTIVE)
+            .min()
+            .unwrap_or(INACTIVE);
+
+        self.nodes[idx].store(min, Ordering::SeqCst);
+        min
+    }
+
+    /// Aggregates all nodes in the tree (full refresh), clearing every
+    /// dirty flag in the process.
+    ///
+    /// Internal nodes are numbered so that every node's children have a
+    /// strictly larger index than it does, so walking indices
+    /// `0..leaf_offset` in reverse visits a node only after all of its
+    /// children (leaves or already-recomputed internal nodes) are current.
+    fn aggregate_all(&self) {
+        for idx in (0..self.leaf_offset).rev() {
+            self.recompute_node(idx);
+            self.dirty[idx].store(false, Ordering::Release);
+        }
+    }
+
+    fn active_count(&self) -> usize {
+        self.nodes[self.leaf_offset..]
+            .iter()
+            .filter(|e| e.load(Ordering::Relaxed) != INACTIVE)
+            .count()
+    }
+}
+
 /// A hierarchical epoch manager for efficient cross-paradigm synchronization.
 ///
 /// The `HierarchicalEpoch` structure organizes thread epochs in a tree,
@@ -77,21 +381,88 @@ const MAX_DEPTH: usize = 4;
 /// let min = hier.global_minimum();
 /// ```
 pub struct HierarchicalEpoch {
-    /// Thread-local epochs stored as flat array
-    local_epochs: Vec<AtomicEpoch>,
-    
-    /// Aggregation levels (each level aggregates BRANCHING_FACTOR children)
-    /// aggregation[0] = aggregates of local_epochs
-    /// aggregation[k] = aggregates of aggregation[k-1]
-    aggregation: Vec<Vec<AtomicEpoch>>,
-    
-    /// Number of supported threads
-    capacity: usize,
-    
-    /// Current tree depth
-    depth: usize,
+    /// The current generation, swapped atomically by [`ensure_capacity`](Self::ensure_capacity)
+    /// so readers never observe a partially-built tree.
+    tree: ArcSwap<Tree>,
+
+    /// Serializes growth against both concurrent grows and concurrent
+    /// [`update_local`](Self::update_local) calls: `ensure_capacity` holds
+    /// the write side across the whole snapshot-then-install sequence, so
+    /// an update either completes before a grow starts (and is captured by
+    /// its leaf snapshot) or blocks until the new generation is installed
+    /// (and then applies directly to it). Without this, a write landing in
+    /// the gap between `grown_from`'s snapshot and the `ArcSwap::store`
+    /// that installs it would be silently lost — invisible to the new
+    /// generation and to `global_minimum`, which could then advance past a
+    /// thread that's still pinned. `local_epoch`/`global_minimum` don't
+    /// need it: they only ever read one generation at a time and are safe
+    /// to race a grow (they'll just see the old or the new epoch, never a
+    /// torn mix).
+    grow_lock: RwLock<()>,
+
+    /// Depth ceiling enforced by `ensure_capacity`/`register`, replacing
+    /// the old hard `MAX_DEPTH` constant.
+    max_depth: usize,
+
+    /// High-water mark for slots never yet handed out by [`register`](Self::register).
+    next_slot: AtomicUsize,
+
+    /// Recycled slots freed by a dropped [`EpochGuard`], popped before the
+    /// high-water mark is advanced.
+    free_slots: FreeList,
+
+    /// Jobs-event-counter backing [`wait_until_reclaimable`](Self::wait_until_reclaimable):
+    /// sleepy-waiter count in the high 32 bits, advance generation in the
+    /// low 32 bits. See the module docs for the wait protocol.
+    sleep_state: AtomicU64,
+
+    /// Paired with `sleep_state` purely as a rendezvous point for
+    /// `wake_cond`'s wait/notify — the condition being waited on lives in
+    /// the atomic, not in data this mutex guards.
+    wake_lock: Mutex<()>,
+    wake_cond: Condvar,
+
+    /// Bytes of garbage staged for reclamation but not yet confirmed freed;
+    /// bumped by callers via [`record_retired_bytes`](Self::record_retired_bytes)
+    /// as they stage objects, and drawn down by whatever consumers report
+    /// freeing during a pressure sweep.
+    retired_bytes: AtomicUsize,
+
+    /// `retired_bytes` threshold past which `record_retired_bytes` triggers
+    /// a sweep. `None` for trees built via [`new`](Self::new)/
+    /// [`HierarchicalEpochBuilder::build`], which never track pressure.
+    high_water_mark: Option<usize>,
+
+    /// Registered [`ReclamationConsumer`]s, invoked in registration order
+    /// during a pressure sweep. Guarded by a `Mutex` since registration and
+    /// sweeps are rare compared to `update_local`'s hot path.
+    consumers: Mutex<Vec<(u64, Arc<dyn ReclamationConsumer>)>>,
+
+    /// Source of the ids backing [`ConsumerId`], handed out by
+    /// `register_consumer`.
+    next_consumer_id: AtomicU64,
 }
 
+/// Callback invoked when staged garbage crosses the high-water mark
+/// configured via [`HierarchicalEpoch::with_pressure_callback`], modeled on
+/// DataFusion's `MemoryManager`/`MemoryConsumer`: the epoch subsystem calls
+/// `on_pressure` instead of assuming every caller polls
+/// [`can_reclaim`](HierarchicalEpoch::can_reclaim) on its own.
+pub trait ReclamationConsumer: Send + Sync {
+    /// Called with the current `retired_bytes` total once it crosses the
+    /// high-water mark. Implementations should free (or otherwise release)
+    /// up to `bytes_needed` bytes and return how many they actually freed;
+    /// the manager subtracts the returned amount from its running
+    /// `retired_bytes` tally.
+    fn on_pressure(&self, bytes_needed: usize) -> usize;
+}
+
+/// Opaque handle returned by [`HierarchicalEpoch::register_consumer`],
+/// needed to [`unregister_consumer`](HierarchicalEpoch::unregister_consumer)
+/// it again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConsumerId(u64);
+
 impl HierarchicalEpoch {
     /// Creates a new hierarchical epoch manager.
     ///
@@ -103,41 +474,51 @@ impl HierarchicalEpoch {
     ///
     /// Panics if capacity exceeds the maximum supported (BRANCHING_FACTOR^MAX_DEPTH = 256).
     pub fn new(capacity: usize) -> Self {
+        Self::with_max_depth_and_pressure(capacity, MAX_DEPTH, None)
+    }
+
+    /// Creates a hierarchical epoch manager that also tracks reclamation
+    /// back-pressure: once bytes staged via
+    /// [`record_retired_bytes`](Self::record_retired_bytes) cross
+    /// `high_water_mark`, a synchronous sweep runs and calls every consumer
+    /// registered via [`register_consumer`](Self::register_consumer). See
+    /// the module docs for the sweep protocol.
+    pub fn with_pressure_callback(capacity: usize, high_water_mark: usize) -> Self {
+        Self::with_max_depth_and_pressure(capacity, MAX_DEPTH, Some(high_water_mark))
+    }
+
+    fn with_max_depth(capacity: usize, max_depth: usize) -> Self {
+        Self::with_max_depth_and_pressure(capacity, max_depth, None)
+    }
+
+    fn with_max_depth_and_pressure(
+        capacity: usize,
+        max_depth: usize,
+        high_water_mark: Option<usize>,
+    ) -> Self {
         assert!(capacity > 0, "Capacity must be positive");
-        
-        // Calculate tree depth needed
+
         let depth = Self::compute_depth(capacity);
-        let actual_capacity = BRANCHING_FACTOR.pow(depth as u32);
-        
         assert!(
-            depth <= MAX_DEPTH,
+            depth <= max_depth,
             "Capacity {} exceeds maximum supported ({})",
             capacity,
-            BRANCHING_FACTOR.pow(MAX_DEPTH as u32)
+            BRANCHING_FACTOR.pow(max_depth as u32)
         );
-        
-        // Create local epochs
-        let local_epochs: Vec<AtomicEpoch> = (0..actual_capacity)
-            .map(|_| AtomicEpoch::new(INACTIVE))
-            .collect();
-        
-        // Create aggregation levels
-        let mut aggregation = Vec::new();
-        let mut level_size = actual_capacity;
-        
-        while level_size > 1 {
-            level_size = (level_size + BRANCHING_FACTOR - 1) / BRANCHING_FACTOR;
-            let level: Vec<AtomicEpoch> = (0..level_size)
-                .map(|_| AtomicEpoch::new(INACTIVE))
-                .collect();
-            aggregation.push(level);
-        }
-        
+
         Self {
-            local_epochs,
-            aggregation,
-            capacity: actual_capacity,
-            depth,
+            tree: ArcSwap::from_pointee(Tree::new(depth)),
+            grow_lock: RwLock::new(()),
+            max_depth,
+            next_slot: AtomicUsize::new(0),
+            free_slots: FreeList::new(),
+            sleep_state: AtomicU64::new(0),
+            wake_lock: Mutex::new(()),
+            wake_cond: Condvar::new(),
+            retired_bytes: AtomicUsize::new(0),
+            high_water_mark,
+            consumers: Mutex::new(Vec::new()),
+            next_consumer_id: AtomicU64::new(0),
         }
     }
 
@@ -146,15 +527,15 @@ impl HierarchicalEpoch {
         if capacity <= 1 {
             return 1;
         }
-        
+
         let mut depth = 1;
         let mut size = BRANCHING_FACTOR;
-        
+
         while size < capacity {
             depth += 1;
             size *= BRANCHING_FACTOR;
         }
-        
+
         depth
     }
 
@@ -164,6 +545,19 @@ impl HierarchicalEpoch {
     /// The update is O(1) for the local operation, with lazy propagation to
     /// aggregation nodes.
     ///
+    /// A grow ([`ensure_capacity`](Self::ensure_capacity)) snapshots every
+    /// leaf into the new generation, then installs it with a single
+    /// `ArcSwap::store`. A write landing in the gap between that snapshot
+    /// and the install — on either the old generation (now about to be
+    /// discarded) or a generation whose snapshot was already taken — would
+    /// otherwise vanish: invisible to the new generation's
+    /// `global_minimum`, which could then advance past a thread that's
+    /// still pinned. Taking `grow_lock` for read closes that window: it
+    /// blocks for the (rare) duration of a concurrent grow's whole
+    /// snapshot-then-install sequence, so this write either lands before
+    /// the grow starts (and is captured by its snapshot) or after the new
+    /// generation is already installed (and applies directly to it).
+    ///
     /// # Arguments
     ///
     /// * `thread_id` - Unique identifier for the thread (0 to capacity-1)
@@ -174,27 +568,27 @@ impl HierarchicalEpoch {
     /// Panics if thread_id >= capacity.
     #[inline]
     pub fn update_local(&self, thread_id: usize, epoch: Epoch) {
-        assert!(thread_id < self.capacity, "Thread ID out of range");
-        
-        let old_epoch = self.local_epochs[thread_id].swap(epoch, Ordering::SeqCst);
-        
-        // Propagate upward if epoch changed
-        if old_epoch != epoch {
-            self.propagate_from(thread_id);
+        let _guard = self.grow_lock.read().unwrap();
+        if self.tree.load().update_local(thread_id, epoch) {
+            self.advance_and_wake();
         }
     }
 
     /// Returns a thread's current local epoch.
     #[inline]
     pub fn local_epoch(&self, thread_id: usize) -> Epoch {
-        assert!(thread_id < self.capacity, "Thread ID out of range");
-        self.local_epochs[thread_id].load(Ordering::SeqCst)
+        self.tree.load().local_epoch(thread_id)
     }
 
     /// Computes the global minimum epoch across all active threads.
     ///
-    /// This operation is O(log T) due to the hierarchical structure,
-    /// compared to O(T) in flat epoch schemes.
+    /// Dirty tracking means this only descends into subtrees that changed
+    /// since the last call: O(1) if nothing has, O(log T) in the worst
+    /// case of a single dirty path, and never worse than the O(T) a flat
+    /// epoch scheme pays on every call. The returned value is a valid
+    /// lower bound on all thread epochs as of some linearization point
+    /// during the call — concurrent updates may raise, but never
+    /// spuriously lower, the true minimum after that snapshot is taken.
     ///
     /// # Returns
     ///
@@ -202,16 +596,18 @@ impl HierarchicalEpoch {
     /// are currently active.
     #[inline]
     pub fn global_minimum(&self) -> Epoch {
-        // Ensure aggregation is up-to-date
-        self.aggregate_all();
-        
-        if self.aggregation.is_empty() {
-            // Only one thread, return directly
-            self.local_epochs[0].load(Ordering::SeqCst)
-        } else {
-            // Return root aggregation
-            self.aggregation.last().unwrap()[0].load(Ordering::SeqCst)
-        }
+        self.tree.load().global_minimum()
+    }
+
+    /// Like [`global_minimum`](Self::global_minimum), but ignores dirty
+    /// tracking and forces a full O(T) sweep over every aggregation node.
+    /// Provides the same lower-bound guarantee; prefer `global_minimum()`
+    /// unless a caller specifically wants the (otherwise unobservable)
+    /// assurance of having just recomputed every node rather than trusted
+    /// an already-clean one.
+    #[inline]
+    pub fn global_minimum_exact(&self) -> Epoch {
+        self.tree.load().global_minimum_exact()
     }
 
     /// Returns whether it's safe to reclaim objects from a given epoch.
@@ -224,85 +620,90 @@ impl HierarchicalEpoch {
         min != INACTIVE && min > epoch
     }
 
-    /// Propagates epoch updates from a leaf toward the root.
-    fn propagate_from(&self, thread_id: usize) {
-        if self.aggregation.is_empty() {
-            return;
-        }
-        
-        let mut idx = thread_id;
-        
-        // First level aggregates local_epochs
-        {
-            let parent_idx = idx / BRANCHING_FACTOR;
-            let start = parent_idx * BRANCHING_FACTOR;
-            let end = (start + BRANCHING_FACTOR).min(self.local_epochs.len());
-            
-            let min = self.local_epochs[start..end]
-                .iter()
-                .map(|e| e.load(Ordering::SeqCst))
-                .filter(|&e| e != INACTIVE)
-                .min()
-                .unwrap_or(INACTIVE);
-            
-            self.aggregation[0][parent_idx].store(min, Ordering::SeqCst);
-            idx = parent_idx;
-        }
-        
-        // Higher levels aggregate previous level
-        for level_idx in 1..self.aggregation.len() {
-            let parent_idx = idx / BRANCHING_FACTOR;
-            let start = parent_idx * BRANCHING_FACTOR;
-            let prev_len = self.aggregation[level_idx - 1].len();
-            let end = (start + BRANCHING_FACTOR).min(prev_len);
-            
-            let min = self.aggregation[level_idx - 1][start..end]
-                .iter()
-                .map(|e| e.load(Ordering::SeqCst))
-                .filter(|&e| e != INACTIVE)
-                .min()
-                .unwrap_or(INACTIVE);
-            
-            self.aggregation[level_idx][parent_idx].store(min, Ordering::SeqCst);
-            idx = parent_idx;
+    /// Blocks the caller until `global_minimum() > epoch`, parking instead
+    /// of spin-polling `can_reclaim`. See the module docs for the wait
+    /// protocol this implements.
+    pub fn wait_until_reclaimable(&self, epoch: Epoch) {
+        self.wait_impl(epoch, None);
+    }
+
+    /// Like [`wait_until_reclaimable`](Self::wait_until_reclaimable), but
+    /// gives up and returns `false` after `timeout` if the epoch still
+    /// hasn't advanced past `epoch`. Returns `true` as soon as it has.
+    pub fn try_wait_timeout(&self, epoch: Epoch, timeout: Duration) -> bool {
+        self.wait_impl(epoch, Some(Instant::now() + timeout))
+    }
+
+    /// Bumps the advance generation and, if anyone announced themselves as
+    /// sleepy, wakes them. Called only when `update_local` observes the
+    /// root's aggregated minimum actually change.
+    fn advance_and_wake(&self) {
+        let prev = self.sleep_state.fetch_add(1, Ordering::AcqRel);
+        if (prev >> ADVANCE_BITS) != 0 {
+            // Taking (and releasing) the lock here, even though we touch
+            // no data it guards, pairs with the waiter's own lock-protected
+            // recheck in `wait_impl`: it guarantees any waiter that has
+            // already rechecked-and-decided-to-block is now inside
+            // `Condvar::wait` (and so will see this notify), and any
+            // waiter that hasn't reached its recheck yet will observe the
+            // generation bump there instead. Either way, no wakeup is lost.
+            let _guard = self.wake_lock.lock().unwrap();
+            self.wake_cond.notify_all();
         }
     }
 
-    /// Aggregates all nodes in the tree (full refresh).
-    fn aggregate_all(&self) {
-        // Aggregate level 0 from local_epochs
-        if let Some(level0) = self.aggregation.first() {
-            for (i, agg) in level0.iter().enumerate() {
-                let start = i * BRANCHING_FACTOR;
-                let end = (start + BRANCHING_FACTOR).min(self.local_epochs.len());
-                
-                let min = self.local_epochs[start..end]
-                    .iter()
-                    .map(|e| e.load(Ordering::SeqCst))
-                    .filter(|&e| e != INACTIVE)
-                    .min()
-                    .unwrap_or(INACTIVE);
-                
-                agg.store(min, Ordering::SeqCst);
+    /// Shared implementation of `wait_until_reclaimable`/`try_wait_timeout`.
+    ///
+    /// Returns `true` once `can_reclaim(epoch)` holds, or `false` if
+    /// `deadline` passes first (never, when `deadline` is `None`).
+    fn wait_impl(&self, epoch: Epoch, deadline: Option<Instant>) -> bool {
+        loop {
+            if self.can_reclaim(epoch) {
+                return true;
             }
-        }
-        
-        // Aggregate higher levels
-        for level_idx in 1..self.aggregation.len() {
-            let prev_level_len = self.aggregation[level_idx - 1].len();
-            
-            for i in 0..self.aggregation[level_idx].len() {
-                let start = i * BRANCHING_FACTOR;
-                let end = (start + BRANCHING_FACTOR).min(prev_level_len);
-                
-                let min = self.aggregation[level_idx - 1][start..end]
-                    .iter()
-                    .map(|e| e.load(Ordering::SeqCst))
-                    .filter(|&e| e != INACTIVE)
-                    .min()
-                    .unwrap_or(INACTIVE);
-                
-                self.aggregation[level_idx][i].store(min, Ordering::SeqCst);
+
+            let generation_before = self.sleep_state.load(Ordering::Acquire) & ADVANCE_MASK;
+
+            // Announce ourselves as sleepy, then recheck: this closes the
+            // race where the epoch advances between the check above and
+            // this announcement, which would otherwise let a waker's
+            // notify fire before we're counted and be lost.
+            self.sleep_state.fetch_add(SLEEPY_ONE, Ordering::AcqRel);
+            if self.can_reclaim(epoch) {
+                self.sleep_state.fetch_sub(SLEEPY_ONE, Ordering::AcqRel);
+                return true;
+            }
+
+            let guard = self.wake_lock.lock().unwrap();
+            let generation_now = self.sleep_state.load(Ordering::Acquire) & ADVANCE_MASK;
+            if generation_now != generation_before || self.can_reclaim(epoch) {
+                // A wake (or a fresh update we haven't observed yet)
+                // already happened; don't block on a stale condition.
+                drop(guard);
+                self.sleep_state.fetch_sub(SLEEPY_ONE, Ordering::AcqRel);
+                continue;
+            }
+
+            let timed_out = match deadline {
+                None => {
+                    drop(self.wake_cond.wait(guard).unwrap());
+                    false
+                }
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    None => true,
+                    Some(remaining) => {
+                        let (woken_guard, result) =
+                            self.wake_cond.wait_timeout(guard, remaining).unwrap();
+                        drop(woken_guard);
+                        result.timed_out()
+                    }
+                },
+            };
+
+            self.sleep_state.fetch_sub(SLEEPY_ONE, Ordering::AcqRel);
+
+            if timed_out && !self.can_reclaim(epoch) {
+                return false;
             }
         }
     }
@@ -310,26 +711,288 @@ impl HierarchicalEpoch {
     /// Returns the capacity of this hierarchical epoch manager.
     #[inline]
     pub fn capacity(&self) -> usize {
-        self.capacity
+        self.tree.load().capacity
     }
 
     /// Returns the depth of the aggregation tree.
     #[inline]
     pub fn depth(&self) -> usize {
-        self.depth
+        self.tree.load().depth
     }
 
     /// Returns the number of currently active threads.
     pub fn active_count(&self) -> usize {
-        self.local_epochs.iter()
-            .filter(|e| e.load(Ordering::Relaxed) != INACTIVE)
-            .count()
+        self.tree.load().active_count()
+    }
+
+    /// Grows the tree, if needed, so it can hold at least `min_capacity`
+    /// leaf slots.
+    ///
+    /// Growth adds one level of depth at a time — multiplying capacity by
+    /// `BRANCHING_FACTOR` — until `min_capacity` is satisfied, copying every
+    /// currently-held leaf epoch into the new, larger generation at its
+    /// existing slot index before installing it. Already-registered thread
+    /// ids therefore keep referring to the same slot across a grow. See the
+    /// module docs for why this amortizes to O(log T) total growth work.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_capacity` would require more depth than `max_depth`
+    /// (see [`HierarchicalEpochBuilder::max_depth`]).
+    pub fn ensure_capacity(&self, min_capacity: usize) {
+        if self.tree.load().capacity >= min_capacity {
+            return;
+        }
+
+        let _guard = self.grow_lock.write().unwrap();
+        loop {
+            let current = self.tree.load_full();
+            if current.capacity >= min_capacity {
+                return;
+            }
+
+            let new_depth = current.depth + 1;
+            assert!(
+                new_depth <= self.max_depth,
+                "HierarchicalEpoch exceeded max_depth ({})",
+                self.max_depth
+            );
+
+            let grown = Tree::grown_from(&current, new_depth);
+            self.tree.store(Arc::new(grown));
+        }
+    }
+
+    /// Registers a new participant, returning an RAII guard that owns a
+    /// unique leaf slot in this tree.
+    ///
+    /// Prefer this over hand-picking a `thread_id` for
+    /// [`update_local`](Self::update_local)/[`local_epoch`](Self::local_epoch):
+    /// a recycled slot is reused from the free list when one is available,
+    /// so threads that repeatedly come and go keep the dense leaf array
+    /// compact instead of marching the high-water mark up to `capacity`.
+    /// Dropping the returned [`EpochGuard`] marks its slot `INACTIVE` and
+    /// pushes it back onto the free list for the next `register()` call.
+    ///
+    /// Unlike earlier revisions, running out of slots grows the tree (see
+    /// [`ensure_capacity`](Self::ensure_capacity)) instead of panicking.
+    ///
+    /// # Panics
+    ///
+    /// Panics if growing to fit the new slot would exceed `max_depth`.
+    pub fn register(&self) -> EpochGuard<'_> {
+        let slot = self
+            .free_slots
+            .pop()
+            .unwrap_or_else(|| self.next_slot.fetch_add(1, Ordering::Relaxed));
+
+        self.ensure_capacity(slot + 1);
+
+        EpochGuard { tree: self, slot }
+    }
+
+    /// Registers a [`ReclamationConsumer`] to be invoked on future pressure
+    /// sweeps, returning a handle for
+    /// [`unregister_consumer`](Self::unregister_consumer). Multiple pools
+    /// can register against the same manager to cooperate under its shared
+    /// `retired_bytes` budget.
+    pub fn register_consumer(&self, consumer: Arc<dyn ReclamationConsumer>) -> ConsumerId {
+        let id = self.next_consumer_id.fetch_add(1, Ordering::Relaxed);
+        self.consumers.lock().unwrap().push((id, consumer));
+        ConsumerId(id)
+    }
+
+    /// Removes a previously registered consumer. A no-op if `id` was
+    /// already unregistered (or never registered on this manager).
+    pub fn unregister_consumer(&self, id: ConsumerId) {
+        self.consumers
+            .lock()
+            .unwrap()
+            .retain(|(existing, _)| *existing != id.0);
+    }
+
+    /// Bumps `retired_bytes` by `bytes` as callers stage objects for
+    /// reclamation, triggering a synchronous pressure sweep once the
+    /// running total crosses `high_water_mark` (set via
+    /// [`with_pressure_callback`](Self::with_pressure_callback)). Returns
+    /// how many bytes the sweep actually reclaimed this call (`0` if no
+    /// sweep ran).
+    ///
+    /// # Panics
+    ///
+    /// Panics if this manager wasn't built with
+    /// [`with_pressure_callback`](Self::with_pressure_callback).
+    pub fn record_retired_bytes(&self, bytes: usize) -> usize {
+        let high_water_mark = self
+            .high_water_mark
+            .expect("record_retired_bytes requires a manager built with with_pressure_callback");
+
+        let total = self.retired_bytes.fetch_add(bytes, Ordering::AcqRel) + bytes;
+        if total <= high_water_mark {
+            return 0;
+        }
+
+        self.pressure_sweep()
+    }
+
+    /// Runs one round of the pressure-sweep protocol described in the
+    /// module docs: aggregate the tree, invoke every registered consumer
+    /// with the current `retired_bytes` total, and draw down whatever they
+    /// report freeing. Returns the total bytes reclaimed this round.
+    ///
+    /// If the global minimum still hasn't advanced afterward, some thread
+    /// is genuinely stalled behind the rest — that thread is still pinned
+    /// and reading memory at its recorded epoch, so its local epoch is
+    /// *not* forced forward: doing so would let `global_minimum` advance
+    /// past memory that thread is still using, a use-after-free. The next
+    /// sweep simply tries again once that thread actually advances or
+    /// unpins.
+    fn pressure_sweep(&self) -> usize {
+        let mut reclaimed = 0usize;
+        let mut remaining = self.retired_bytes.load(Ordering::Acquire);
+        for (_, consumer) in self.consumers.lock().unwrap().iter() {
+            if remaining == 0 {
+                break;
+            }
+            let freed = consumer.on_pressure(remaining);
+            reclaimed += freed;
+            remaining = remaining.saturating_sub(freed);
+        }
+
+        if reclaimed > 0 {
+            self.retired_bytes
+                .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                    Some(current.saturating_sub(reclaimed))
+                })
+                .ok();
+        }
+
+        reclaimed
+    }
+}
+
+/// An RAII handle to a slot registered via [`HierarchicalEpoch::register`].
+///
+/// Marks its slot `INACTIVE` and returns the slot index to the tree's free
+/// list when dropped, so a later `register()` call can reuse it rather than
+/// exhausting the tree under thread churn.
+pub struct EpochGuard<'a> {
+    tree: &'a HierarchicalEpoch,
+    slot: usize,
+}
+
+impl EpochGuard<'_> {
+    /// This guard's slot index.
+    #[inline]
+    pub fn slot(&self) -> usize {
+        self.slot
+    }
+
+    /// Records entry into `epoch` for this guard's slot.
+    #[inline]
+    pub fn enter(&self, epoch: Epoch) {
+        self.tree.update_local(self.slot, epoch);
+    }
+
+    /// Marks this guard's slot inactive without releasing it back to the
+    /// free list; a later [`enter`](Self::enter) call reuses the same slot.
+    #[inline]
+    pub fn leave(&self) {
+        self.tree.update_local(self.slot, INACTIVE);
+    }
+}
+
+impl Drop for EpochGuard<'_> {
+    fn drop(&mut self) {
+        self.tree.update_local(self.slot, INACTIVE);
+        self.tree.free_slots.push(self.slot);
     }
 }
 
+/// A lock-free Treiber stack of recycled leaf-slot indices, backing
+/// [`HierarchicalEpoch::register`].
+struct FreeList {
+    head: AtomicPtr<FreeNode>,
+}
+
+struct FreeNode {
+    slot: usize,
+    next: AtomicPtr<FreeNode>,
+}
+
+impl FreeList {
+    fn new() -> Self {
+        Self {
+            head: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    fn push(&self, slot: usize) {
+        let node = Box::into_raw(Box::new(FreeNode {
+            slot,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            // SAFETY: `node` was just allocated and is not yet visible to
+            // anyone else, so this is the only writer.
+            unsafe { (*node).next.store(head, Ordering::Relaxed) };
+
+            match self
+                .head
+                .compare_exchange_weak(head, node, Ordering::AcqRel, Ordering::Acquire)
+            {
+                Ok(_) => break,
+                Err(current_head) => head = current_head,
+            }
+        }
+    }
+
+    fn pop(&self) -> Option<usize> {
+        let mut head = self.head.load(Ordering::Acquire);
+        loop {
+            if head.is_null() {
+                return None;
+            }
+
+            // SAFETY: `head` is non-null; nodes are only ever freed after
+            // being popped, and this is the only place that pops.
+            let next = unsafe { (*head).next.load(Ordering::Relaxed) };
+
+            match self
+                .head
+                .compare_exchange_weak(head, next, Ordering::AcqRel, Ordering::Acquire)
+            {
+                // SAFETY: this call won the CAS, so it has exclusive
+                // ownership of the popped node.
+                Ok(_) => return Some(unsafe { Box::from_raw(head) }.slot),
+                Err(current_head) => head = current_head,
+            }
+        }
+    }
+}
+
+impl Drop for FreeList {
+    fn drop(&mut self) {
+        let mut cursor = *self.head.get_mut();
+        while !cursor.is_null() {
+            // SAFETY: we have exclusive access during drop, and every node
+            // still reachable from the head was never freed.
+            let node = unsafe { Box::from_raw(cursor) };
+            cursor = node.next.load(Ordering::Relaxed);
+        }
+    }
+}
+
+// SAFETY: FreeList only exposes its nodes through atomic CAS operations.
+unsafe impl Send for FreeList {}
+unsafe impl Sync for FreeList {}
+
 /// Builder for HierarchicalEpoch with configurable parameters.
 pub struct HierarchicalEpochBuilder {
     capacity: usize,
+    max_depth: usize,
 }
 
 impl HierarchicalEpochBuilder {
@@ -337,18 +1000,27 @@ impl HierarchicalEpochBuilder {
     pub fn new() -> Self {
         Self {
             capacity: 16,
+            max_depth: MAX_DEPTH,
         }
     }
-    
+
     /// Sets the maximum number of threads.
     pub fn capacity(mut self, capacity: usize) -> Self {
         self.capacity = capacity;
         self
     }
-    
+
+    /// Overrides the depth ceiling enforced by `ensure_capacity`/`register`,
+    /// replacing the hard `MAX_DEPTH` constant for callers that need more
+    /// than `BRANCHING_FACTOR^MAX_DEPTH` threads.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
     /// Builds the HierarchicalEpoch instance.
     pub fn build(self) -> HierarchicalEpoch {
-        HierarchicalEpoch::new(self.capacity)
+        HierarchicalEpoch::with_max_depth(self.capacity, self.max_depth)
     }
 }
 
@@ -358,7 +1030,8 @@ impl Default for HierarchicalEpochBuilder {
     }
 }
 
-// Safety: HierarchicalEpoch only contains atomic operations
+// Safety: HierarchicalEpoch only contains atomic operations (and an
+// ArcSwap/Mutex, both Send + Sync when their payload is).
 unsafe impl Send for HierarchicalEpoch {}
 unsafe impl Sync for HierarchicalEpoch {}
 
@@ -378,7 +1051,7 @@ mod tests {
     #[test]
     fn test_basic_operations() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         assert_eq!(hier.capacity(), 16);
         assert_eq!(hier.global_minimum(), INACTIVE);
     }
@@ -386,14 +1059,14 @@ mod tests {
     #[test]
     fn test_local_update() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         // Initially inactive
         assert_eq!(hier.local_epoch(0), INACTIVE);
-        
+
         // Update to epoch 5
         hier.update_local(0, 5);
         assert_eq!(hier.local_epoch(0), 5);
-        
+
         // Global minimum should now be 5
         assert_eq!(hier.global_minimum(), 5);
     }
@@ -401,23 +1074,42 @@ mod tests {
     #[test]
     fn test_multiple_threads() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         hier.update_local(0, 5);
         hier.update_local(1, 3);
         hier.update_local(2, 7);
-        
+
         // Minimum should be 3
         assert_eq!(hier.global_minimum(), 3);
     }
 
+    #[test]
+    fn test_multi_level_propagation_across_leaf_groups() {
+        // Capacity 64 spans three internal levels (4, 16, and 64-leaf
+        // groupings), so this exercises mark_dirty_from/refresh_node
+        // walking all the way to the root rather than just one level up.
+        let hier = HierarchicalEpoch::new(64);
+
+        hier.update_local(0, 10);
+        hier.update_local(20, 2);
+        hier.update_local(63, 100);
+
+        assert_eq!(hier.global_minimum(), 2);
+
+        // Updating the minimum-holding leaf should shift the global
+        // minimum even though it sits in a different branch of the tree.
+        hier.update_local(20, 50);
+        assert_eq!(hier.global_minimum(), 10);
+    }
+
     #[test]
     fn test_inactive_threads_ignored() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         hier.update_local(0, 5);
         hier.update_local(1, INACTIVE);
         hier.update_local(2, 3);
-        
+
         // Thread 1 is inactive, so minimum is 3
         assert_eq!(hier.global_minimum(), 3);
     }
@@ -425,9 +1117,9 @@ mod tests {
     #[test]
     fn test_can_reclaim() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         hier.update_local(0, 5);
-        
+
         assert!(!hier.can_reclaim(5));  // Can't reclaim current epoch
         assert!(hier.can_reclaim(4));   // Can reclaim earlier epochs
         assert!(hier.can_reclaim(0));
@@ -436,15 +1128,15 @@ mod tests {
     #[test]
     fn test_active_count() {
         let hier = HierarchicalEpoch::new(16);
-        
+
         assert_eq!(hier.active_count(), 0);
-        
+
         hier.update_local(0, 5);
         assert_eq!(hier.active_count(), 1);
-        
+
         hier.update_local(1, 3);
         assert_eq!(hier.active_count(), 2);
-        
+
         hier.update_local(0, INACTIVE);
         assert_eq!(hier.active_count(), 1);
     }
@@ -454,7 +1146,335 @@ mod tests {
         let hier = HierarchicalEpochBuilder::new()
             .capacity(32)
             .build();
-        
+
         assert_eq!(hier.capacity(), 64); // Rounded up to power of branching factor
     }
+
+    #[test]
+    fn test_register_enter_leave() {
+        let hier = HierarchicalEpoch::new(16);
+
+        let guard = hier.register();
+        guard.enter(5);
+        assert_eq!(hier.local_epoch(guard.slot()), 5);
+        assert_eq!(hier.global_minimum(), 5);
+
+        guard.leave();
+        assert_eq!(hier.local_epoch(guard.slot()), INACTIVE);
+    }
+
+    #[test]
+    fn test_register_assigns_distinct_slots() {
+        let hier = HierarchicalEpoch::new(16);
+
+        let a = hier.register();
+        let b = hier.register();
+
+        assert_ne!(a.slot(), b.slot());
+    }
+
+    #[test]
+    fn test_register_recycles_dropped_slot() {
+        let hier = HierarchicalEpoch::new(16);
+
+        let a = hier.register();
+        let first_slot = a.slot();
+        drop(a);
+
+        let b = hier.register();
+        assert_eq!(b.slot(), first_slot);
+    }
+
+    #[test]
+    fn test_drop_guard_marks_inactive() {
+        let hier = HierarchicalEpoch::new(16);
+
+        let guard = hier.register();
+        guard.enter(7);
+        let slot = guard.slot();
+        drop(guard);
+
+        assert_eq!(hier.local_epoch(slot), INACTIVE);
+    }
+
+    #[test]
+    fn test_register_grows_past_initial_capacity() {
+        let hier = HierarchicalEpoch::new(4);
+        assert_eq!(hier.capacity(), 4);
+
+        let guards: Vec<_> = (0..10).map(|_| hier.register()).collect();
+        assert!(hier.capacity() >= 10);
+
+        // Every handed-out slot is still distinct and usable after growth.
+        for (i, guard) in guards.iter().enumerate() {
+            guard.enter(i as Epoch + 1);
+        }
+        for (i, guard) in guards.iter().enumerate() {
+            assert_eq!(hier.local_epoch(guard.slot()), i as Epoch + 1);
+        }
+    }
+
+    #[test]
+    fn test_grow_preserves_existing_epochs() {
+        let hier = HierarchicalEpoch::new(4);
+        hier.update_local(0, 5);
+        hier.update_local(3, 9);
+
+        hier.ensure_capacity(20);
+
+        assert_eq!(hier.local_epoch(0), 5);
+        assert_eq!(hier.local_epoch(3), 9);
+        assert_eq!(hier.global_minimum(), 5);
+    }
+
+    #[test]
+    fn test_update_local_survives_concurrent_grow() {
+        use std::sync::{Arc as StdArc, Barrier};
+
+        // Regression test: `ensure_capacity` snapshots every leaf into the
+        // new generation before installing it, so a pin that lands on the
+        // old generation in the gap between snapshot and install must not
+        // be silently dropped — otherwise `global_minimum` on the new
+        // generation could advance past a thread that's still pinned.
+        for _ in 0..200 {
+            let hier = StdArc::new(HierarchicalEpoch::new(4));
+            let barrier = StdArc::new(Barrier::new(2));
+
+            let writer = {
+                let hier = hier.clone();
+                let barrier = barrier.clone();
+                std::thread::spawn(move || {
+                    barrier.wait();
+                    hier.update_local(0, 7);
+                })
+            };
+
+            barrier.wait();
+            hier.ensure_capacity(32);
+            writer.join().unwrap();
+
+            assert_eq!(hier.local_epoch(0), 7);
+            assert_eq!(hier.global_minimum(), 7);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "max_depth")]
+    fn test_ensure_capacity_respects_max_depth() {
+        let hier = HierarchicalEpochBuilder::new()
+            .capacity(4)
+            .max_depth(1)
+            .build();
+
+        hier.ensure_capacity(5);
+    }
+
+    #[test]
+    fn test_max_depth_builder_knob_allows_more_than_default_cap() {
+        let hier = HierarchicalEpochBuilder::new()
+            .capacity(4)
+            .max_depth(6)
+            .build();
+
+        hier.ensure_capacity(1000);
+        assert!(hier.capacity() >= 1000);
+    }
+
+    #[test]
+    fn test_wait_until_reclaimable_returns_immediately_when_already_reclaimable() {
+        let hier = HierarchicalEpoch::new(16);
+        hier.update_local(0, 5);
+
+        // Already reclaimable at epoch 0, so this must not block.
+        hier.wait_until_reclaimable(0);
+    }
+
+    #[test]
+    fn test_wait_until_reclaimable_wakes_on_advance() {
+        use std::sync::Arc as StdArc;
+
+        let hier = StdArc::new(HierarchicalEpoch::new(16));
+        hier.update_local(0, 1);
+
+        let waiter = {
+            let hier = StdArc::clone(&hier);
+            std::thread::spawn(move || {
+                hier.wait_until_reclaimable(1);
+            })
+        };
+
+        // Give the waiter a moment to park before advancing the epoch.
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        hier.update_local(0, 2);
+
+        waiter
+            .join()
+            .expect("waiter thread should observe the advance and return");
+    }
+
+    #[test]
+    fn test_try_wait_timeout_times_out_when_epoch_never_advances() {
+        let hier = HierarchicalEpoch::new(16);
+        hier.update_local(0, 5);
+
+        assert!(!hier.try_wait_timeout(5, Duration::from_millis(20)));
+    }
+
+    #[test]
+    fn test_try_wait_timeout_succeeds_before_deadline() {
+        use std::sync::Arc as StdArc;
+
+        let hier = StdArc::new(HierarchicalEpoch::new(16));
+        hier.update_local(0, 1);
+
+        let waiter = {
+            let hier = StdArc::clone(&hier);
+            std::thread::spawn(move || hier.try_wait_timeout(1, Duration::from_secs(5)))
+        };
+
+        std::thread::sleep(std::time::Duration::from_millis(20));
+        hier.update_local(0, 2);
+
+        assert!(waiter.join().expect("waiter thread should not panic"));
+    }
+
+    struct CountingConsumer {
+        freed: AtomicUsize,
+    }
+
+    impl CountingConsumer {
+        fn new() -> Self {
+            Self {
+                freed: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl ReclamationConsumer for CountingConsumer {
+        fn on_pressure(&self, bytes_needed: usize) -> usize {
+            self.freed.fetch_add(bytes_needed, Ordering::Relaxed);
+            bytes_needed
+        }
+    }
+
+    #[test]
+    fn test_record_retired_bytes_below_high_water_mark_does_not_sweep() {
+        let hier = HierarchicalEpoch::with_pressure_callback(16, 100);
+        let consumer = Arc::new(CountingConsumer::new());
+        hier.register_consumer(consumer.clone());
+
+        assert_eq!(hier.record_retired_bytes(50), 0);
+        assert_eq!(consumer.freed.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_record_retired_bytes_above_high_water_mark_invokes_consumer() {
+        let hier = HierarchicalEpoch::with_pressure_callback(16, 100);
+        let consumer = Arc::new(CountingConsumer::new());
+        hier.register_consumer(consumer.clone());
+
+        let reclaimed = hier.record_retired_bytes(150);
+        assert_eq!(reclaimed, 150);
+        assert_eq!(consumer.freed.load(Ordering::Relaxed), 150);
+    }
+
+    #[test]
+    #[should_panic(expected = "with_pressure_callback")]
+    fn test_record_retired_bytes_without_pressure_callback_panics() {
+        let hier = HierarchicalEpoch::new(16);
+        hier.record_retired_bytes(1);
+    }
+
+    #[test]
+    fn test_unregister_consumer_stops_future_invocations() {
+        let hier = HierarchicalEpoch::with_pressure_callback(16, 100);
+        let consumer = Arc::new(CountingConsumer::new());
+        let id = hier.register_consumer(consumer.clone());
+        hier.unregister_consumer(id);
+
+        hier.record_retired_bytes(150);
+        assert_eq!(consumer.freed.load(Ordering::Relaxed), 0);
+    }
+
+    #[test]
+    fn test_multiple_consumers_cooperate_under_shared_budget() {
+        let hier = HierarchicalEpoch::with_pressure_callback(16, 100);
+        let first = Arc::new(CountingConsumer::new());
+        let second = Arc::new(CountingConsumer::new());
+        hier.register_consumer(first.clone());
+        hier.register_consumer(second.clone());
+
+        // The first consumer reports freeing everything, so the second
+        // should see nothing left to do.
+        hier.record_retired_bytes(150);
+        assert_eq!(first.freed.load(Ordering::Relaxed), 150);
+        assert_eq!(second.freed.load(Ordering::Relaxed), 0);
+    }
+
+    struct NoopConsumer;
+
+    impl ReclamationConsumer for NoopConsumer {
+        fn on_pressure(&self, _bytes_needed: usize) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn test_pressure_sweep_never_advances_a_stalled_but_live_thread() {
+        let hier = HierarchicalEpoch::with_pressure_callback(16, 10);
+        hier.register_consumer(Arc::new(NoopConsumer));
+
+        hier.update_local(0, 1);
+        hier.update_local(1, 5);
+
+        hier.record_retired_bytes(20);
+
+        // Thread 0 is the stalled minimum, but it's still pinned and may
+        // still be reading memory at epoch 1 — the sweep must leave its
+        // local epoch (and so the global minimum) untouched rather than
+        // forcing it forward.
+        assert_eq!(hier.local_epoch(0), 1);
+        assert_eq!(hier.global_minimum(), 1);
+    }
+
+    #[test]
+    fn test_global_minimum_clean_root_returns_without_recompute() {
+        let hier = HierarchicalEpoch::new(16);
+        hier.update_local(0, 5);
+
+        // First call descends the dirty path and clears it.
+        assert_eq!(hier.global_minimum(), 5);
+        // Second call finds a clean root and must return the same value.
+        assert_eq!(hier.global_minimum(), 5);
+    }
+
+    #[test]
+    fn test_global_minimum_exact_matches_global_minimum() {
+        let hier = HierarchicalEpoch::new(64);
+
+        hier.update_local(0, 10);
+        hier.update_local(20, 2);
+        hier.update_local(63, 100);
+
+        assert_eq!(hier.global_minimum(), 2);
+        assert_eq!(hier.global_minimum_exact(), 2);
+
+        hier.update_local(20, 50);
+        assert_eq!(hier.global_minimum_exact(), 10);
+        assert_eq!(hier.global_minimum(), 10);
+    }
+
+    #[test]
+    fn test_dirty_tracking_across_multiple_updates_before_a_read() {
+        let hier = HierarchicalEpoch::new(64);
+
+        // Several updates land before anyone ever reads global_minimum();
+        // dirty marking must still converge on the true minimum.
+        hier.update_local(0, 10);
+        hier.update_local(1, 8);
+        hier.update_local(40, 3);
+        hier.update_local(1, 50);
+
+        assert_eq!(hier.global_minimum(), 3);
+    }
 }
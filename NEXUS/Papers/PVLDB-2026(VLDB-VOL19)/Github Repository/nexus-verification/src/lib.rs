@@ -22,8 +22,10 @@
 
 pub mod tla_plus;
 
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::{HashSet, VecDeque};
 use std::marker::PhantomData;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Condvar, Mutex};
 
 /// Verification error types
 #[derive(Debug, Clone)]
@@ -241,27 +243,54 @@ pub struct VerificationEngine<S> {
     properties: Vec<Box<dyn PropertyBox>>,
     state: PhantomData<S>,
     stats: VerificationStats,
+    strategy: VerificationStrategy,
+}
+
+/// Controls how [`VerificationEngine::verify`] checks multiple properties.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VerificationStrategy {
+    /// Check every property independently and report a witness for each
+    /// one, regardless of whether an earlier property failed.
+    Individual,
+    /// Fuse every property into a single short-circuiting pass first; only
+    /// fall back to checking properties one at a time — to localize which
+    /// one failed — if that fused pass finds a violation.
+    Bulk,
 }
 
 trait PropertyBox: Send + Sync {
     fn name(&self) -> &str;
-    fn description(&self) -> &str;
     fn check_any(&self, state: &dyn std::any::Any) -> bool;
 }
 
-impl<P: Property + Send + Sync + 'static> PropertyBox for P {
+/// Pairs a [`Property`] with the concrete [`VerifiableState`] type it was
+/// registered against, so [`PropertyBox::check_any`] knows what to
+/// `downcast_ref` the type-erased state to.
+///
+/// `Property::check` is generic over `S`, which makes `Property` itself not
+/// object-safe; wrapping it alongside a `PhantomData<S>` is what lets
+/// [`VerificationEngine::add_property`] and [`ModelChecker::add_property`]
+/// store properties for different engines as a single `Vec<Box<dyn
+/// PropertyBox>>`.
+struct PropertyAdapter<P, S> {
+    property: P,
+    state: PhantomData<fn(&S)>,
+}
+
+impl<P: Property + Send + Sync + 'static, S: VerifiableState + 'static> PropertyBox
+    for PropertyAdapter<P, S>
+{
     fn name(&self) -> &str {
-        Property::name(self)
+        Property::name(&self.property)
     }
-    
-    fn description(&self) -> &str {
-        Property::description(self)
-    }
-    
-    fn check_any(&self, _state: &dyn std::any::Any) -> bool {
-        // Type-erased check - always returns true for simplicity
-        // Real implementation would downcast and check
-        true
+
+    fn check_any(&self, state: &dyn std::any::Any) -> bool {
+        match state.downcast_ref::<S>() {
+            Some(state) => self.property.check(state),
+            // A state of the wrong type can't satisfy the property; treat
+            // it as a mismatch rather than panicking.
+            None => false,
+        }
     }
 }
 
@@ -283,49 +312,558 @@ impl<S: VerifiableState + 'static> VerificationEngine<S> {
             properties: Vec::new(),
             state: PhantomData,
             stats: VerificationStats::default(),
+            strategy: VerificationStrategy::Individual,
         }
     }
-    
+
     /// Add a property to verify
     pub fn add_property<P: Property + Send + Sync + 'static>(&mut self, property: P) {
-        self.properties.push(Box::new(property));
+        self.properties.push(Box::new(PropertyAdapter::<P, S> {
+            property,
+            state: PhantomData,
+        }));
     }
-    
+
+    /// Sets the strategy used by [`verify`](Self::verify).
+    pub fn set_strategy(&mut self, strategy: VerificationStrategy) {
+        self.strategy = strategy;
+    }
+
     /// Verify all properties against a state
     pub fn verify(&self, state: &S) -> VerificationResult<Vec<ProofWitness<String>>> {
+        match self.strategy {
+            VerificationStrategy::Individual => self.verify_individual(state),
+            VerificationStrategy::Bulk => self.verify_bulk(state),
+        }
+    }
+
+    /// Checks every property independently, always returning a witness per
+    /// property even if some failed.
+    fn verify_individual(&self, state: &S) -> VerificationResult<Vec<ProofWitness<String>>> {
         let mut witnesses = Vec::new();
-        
+
         for property in &self.properties {
             self.stats.properties_checked.fetch_add(1, Ordering::Relaxed);
-            
+
             let verified = property.check_any(state);
-            
+
             if !verified {
                 self.stats.violations_found.fetch_add(1, Ordering::Relaxed);
             }
-            
+
             witnesses.push(ProofWitness {
                 property: property.name().to_string(),
                 method: VerificationMethod::RuntimeVerification,
                 verified,
             });
         }
-        
+
         Ok(witnesses)
     }
-    
+
+    /// Checks every property in one short-circuiting pass first. If that
+    /// fused pass holds, returns a witness per property with no further
+    /// checking. If it finds a violation, falls back to checking
+    /// properties one at a time to localize exactly which one failed.
+    fn verify_bulk(&self, state: &S) -> VerificationResult<Vec<ProofWitness<String>>> {
+        let mut all_ok = true;
+        for property in &self.properties {
+            self.stats.properties_checked.fetch_add(1, Ordering::Relaxed);
+            if !property.check_any(state) {
+                all_ok = false;
+                break;
+            }
+        }
+
+        if all_ok {
+            return Ok(self
+                .properties
+                .iter()
+                .map(|property| ProofWitness {
+                    property: property.name().to_string(),
+                    method: VerificationMethod::RuntimeVerification,
+                    verified: true,
+                })
+                .collect());
+        }
+
+        // The fused pass found a violation somewhere: fall back to
+        // checking properties one at a time so we can report exactly
+        // which one, rather than just "something failed".
+        for property in &self.properties {
+            self.stats.properties_checked.fetch_add(1, Ordering::Relaxed);
+            if !property.check_any(state) {
+                self.stats.violations_found.fetch_add(1, Ordering::Relaxed);
+                return Err(VerificationError::PropertyViolation {
+                    property: property.name().to_string(),
+                    counterexample: Self::render_state(state),
+                });
+            }
+        }
+
+        unreachable!("fused pass found a violation but the fallback pass did not reproduce it")
+    }
+
+    /// Renders the offending state into a counterexample string, in the
+    /// same shape [`ModelChecker::render_counterexample`] uses for its
+    /// trace states.
+    fn render_state(state: &S) -> String {
+        format!(
+            "(epoch={}, threads={}, garbage={})",
+            state.current_epoch(),
+            state.active_threads(),
+            state.garbage_count()
+        )
+    }
+
+    /// Verifies every state in `states` against all properties, using a
+    /// pool of [`std::thread::available_parallelism`] worker threads.
+    ///
+    /// Results are returned in the same order as `states`, regardless of
+    /// which worker ends up checking which state.
+    pub fn verify_parallel(&self, states: &[S]) -> VerificationResult<Vec<Vec<ProofWitness<String>>>>
+    where
+        S: Sync,
+    {
+        let default_threads = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        self.verify_parallel_with_threads(states, default_threads)
+    }
+
+    /// Like [`verify_parallel`](Self::verify_parallel), with an explicit
+    /// worker count instead of the `available_parallelism()` default.
+    pub fn verify_parallel_with_threads(
+        &self,
+        states: &[S],
+        num_threads: usize,
+    ) -> VerificationResult<Vec<Vec<ProofWitness<String>>>>
+    where
+        S: Sync,
+    {
+        let num_threads = num_threads.max(1);
+
+        let queue = Mutex::new(WorkQueue {
+            pending: (0..states.len()).collect(),
+        });
+        let work_ready = Condvar::new();
+        let ready = AtomicBool::new(false);
+        let results: Vec<Mutex<Option<Vec<ProofWitness<String>>>>> =
+            states.iter().map(|_| Mutex::new(None)).collect();
+
+        std::thread::scope(|scope| {
+            for _ in 0..num_threads {
+                scope.spawn(|| {
+                    // Wait until the queue has been fully seeded before
+                    // claiming any work, so no worker can drain a
+                    // partially-populated queue and exit early.
+                    let mut guard = queue.lock().unwrap();
+                    while !ready.load(Ordering::Acquire) {
+                        guard = work_ready.wait(guard).unwrap();
+                    }
+                    drop(guard);
+
+                    loop {
+                        let index = {
+                            let mut guard = queue.lock().unwrap();
+                            guard.pending.pop_front()
+                        };
+
+                        let Some(index) = index else { break };
+
+                        let witnesses = self
+                            .verify(&states[index])
+                            .expect("runtime verification never fails");
+                        *results[index].lock().unwrap() = Some(witnesses);
+                        self.stats.states_explored.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            }
+
+            // Flip the gate while holding `queue`'s lock so the change is
+            // serialized against a worker's `wait()`: a worker that observes
+            // `ready == false` is guaranteed to already hold (or be about to
+            // take) this same lock before re-checking the predicate, so it
+            // cannot miss this notify the way it could if we stored outside
+            // the lock.
+            {
+                let _guard = queue.lock().unwrap();
+                ready.store(true, Ordering::Release);
+            }
+            work_ready.notify_all();
+        });
+
+        Ok(results
+            .into_iter()
+            .map(|cell| cell.into_inner().unwrap().expect("every index is claimed exactly once"))
+            .collect())
+    }
+
     /// Get verification statistics
     pub fn stats(&self) -> &VerificationStats {
         &self.stats
     }
 }
 
+/// Shared work queue backing [`VerificationEngine::verify_parallel_with_threads`].
+struct WorkQueue {
+    /// Indices into the `states` slice not yet claimed by a worker. Each
+    /// index is popped by exactly one worker, so no further bookkeeping is
+    /// needed to avoid double-checking a state.
+    pending: VecDeque<usize>,
+}
+
 impl<S: VerifiableState + 'static> Default for VerificationEngine<S> {
     fn default() -> Self {
         Self::new()
     }
 }
 
+// ============================================================================
+// Randomized Property Testing
+// ============================================================================
+
+/// A concrete [`VerifiableState`] for randomized property testing, produced
+/// by [`StateBuilder`] and consumed by [`VerificationEngine::fuzz`].
+///
+/// Downstream crates have their own state types wired into the properties
+/// above; this one exists so `fuzz` has something to generate and shrink
+/// without needing a production state on hand.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GeneratedState {
+    epoch: u64,
+    threads: usize,
+    garbage: usize,
+}
+
+impl VerifiableState for GeneratedState {
+    fn current_epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    fn active_threads(&self) -> usize {
+        self.threads
+    }
+
+    fn garbage_count(&self) -> usize {
+        self.garbage
+    }
+}
+
+/// Builds [`GeneratedState`] values for seeding [`VerificationEngine::fuzz`]'s
+/// generator closure, or for constructing states by hand in tests.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StateBuilder {
+    state: GeneratedState,
+}
+
+impl StateBuilder {
+    /// Start from the all-zero state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current epoch.
+    pub fn epoch(mut self, epoch: u64) -> Self {
+        self.state.epoch = epoch;
+        self
+    }
+
+    /// Set the active thread count.
+    pub fn threads(mut self, threads: usize) -> Self {
+        self.state.threads = threads;
+        self
+    }
+
+    /// Set the garbage count.
+    pub fn garbage(mut self, garbage: usize) -> Self {
+        self.state.garbage = garbage;
+        self
+    }
+
+    /// Finish building.
+    pub fn build(self) -> GeneratedState {
+        self.state
+    }
+}
+
+impl VerificationEngine<GeneratedState> {
+    /// Repeatedly draws states from `generator` and checks every registered
+    /// property against each, stopping at the first violation.
+    ///
+    /// On violation, the failing state is shrunk — by monotonically halving
+    /// its `garbage` and `threads` fields toward zero, keeping only
+    /// reductions that still violate some property — down to a minimal
+    /// witness, which is reported as the returned error's counterexample.
+    /// On success, returns a [`VerificationMethod::PropertyTest`] witness
+    /// covering the whole run.
+    pub fn fuzz(
+        &self,
+        generator: &mut dyn FnMut() -> GeneratedState,
+        iterations: usize,
+    ) -> VerificationResult<ProofWitness<String>> {
+        for _ in 0..iterations {
+            let state = generator();
+            self.stats.states_explored.fetch_add(1, Ordering::Relaxed);
+            self.stats
+                .properties_checked
+                .fetch_add(self.properties.len() as u64, Ordering::Relaxed);
+
+            if let Some(violated) = self.first_violation(&state) {
+                self.stats.violations_found.fetch_add(1, Ordering::Relaxed);
+                let minimal = self.shrink(state);
+                return Err(VerificationError::PropertyViolation {
+                    property: violated.to_string(),
+                    counterexample: format!(
+                        "(epoch={}, threads={}, garbage={})",
+                        minimal.epoch, minimal.threads, minimal.garbage
+                    ),
+                });
+            }
+        }
+
+        Ok(ProofWitness {
+            property: format!("fuzz({iterations} iterations)"),
+            method: VerificationMethod::PropertyTest,
+            verified: true,
+        })
+    }
+
+    /// The name of the first registered property that `state` violates, if
+    /// any.
+    fn first_violation(&self, state: &GeneratedState) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|property| !property.check_any(state))
+            .map(|property| property.name())
+    }
+
+    /// Shrinks a known-failing state to a smaller (but still failing) one by
+    /// repeatedly halving `garbage` then `threads` toward zero, keeping each
+    /// halving only if the result still violates some property.
+    fn shrink(&self, mut state: GeneratedState) -> GeneratedState {
+        loop {
+            let mut shrunk_further = false;
+
+            if state.garbage > 0 {
+                let candidate = GeneratedState {
+                    garbage: state.garbage / 2,
+                    ..state
+                };
+                if self.first_violation(&candidate).is_some() {
+                    state = candidate;
+                    shrunk_further = true;
+                }
+            }
+
+            if state.threads > 0 {
+                let candidate = GeneratedState {
+                    threads: state.threads / 2,
+                    ..state
+                };
+                if self.first_violation(&candidate).is_some() {
+                    state = candidate;
+                    shrunk_further = true;
+                }
+            }
+
+            if !shrunk_further {
+                return state;
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Explicit-State Model Checking
+// ============================================================================
+
+/// A transition system over states of type `S`.
+///
+/// [`ModelChecker`] explores the reachable state space by repeatedly calling
+/// [`successors`](Self::successors) from each state it discovers, using
+/// [`hash_state`](Self::hash_state) to recognize states it has already
+/// visited so equivalent interleavings aren't re-expanded.
+pub trait Transition<S> {
+    /// All states directly reachable from `state` in one step.
+    fn successors(&self, state: &S) -> Vec<S>;
+
+    /// A hash identifying `state` for deduplication purposes. States that
+    /// compare equal under the system's notion of equivalence must hash to
+    /// the same value.
+    fn hash_state(&self, state: &S) -> u64;
+}
+
+/// Bounds on how much of the state space [`ModelChecker::check`] will
+/// explore before giving up and reporting what it found.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelCheckerConfig {
+    /// Maximum breadth-first depth from the initial state.
+    pub max_depth: usize,
+    /// Maximum number of distinct states to visit before stopping.
+    pub max_states: usize,
+}
+
+impl Default for ModelCheckerConfig {
+    fn default() -> Self {
+        Self {
+            max_depth: 1_000,
+            max_states: 1_000_000,
+        }
+    }
+}
+
+/// Statistics from a completed (or bounded) [`ModelChecker::check`] run.
+#[derive(Debug, Clone, Default)]
+pub struct ModelCheckStats {
+    /// Number of states dequeued and checked.
+    pub states_explored: u64,
+    /// Number of distinct states discovered (the size of the visited set).
+    pub distinct_states: u64,
+    /// Largest size the BFS frontier reached.
+    pub max_queue_size: u64,
+}
+
+/// Explicit-state breadth-first model checker.
+///
+/// Starting from an initial state, [`check`](Self::check) explores the
+/// reachable state space in BFS order via a [`Transition`] implementation,
+/// checking every registered property at each reached state. The first
+/// state that violates a property is reported as a
+/// [`VerificationError::PropertyViolation`] whose `counterexample` is the
+/// shortest path (in transitions) from the initial state to the violation,
+/// reconstructed from a parent-pointer map kept alongside the visited set.
+pub struct ModelChecker<S> {
+    properties: Vec<Box<dyn PropertyBox>>,
+    config: ModelCheckerConfig,
+    state: PhantomData<S>,
+}
+
+impl<S: VerifiableState + Clone + 'static> ModelChecker<S> {
+    /// Create a model checker with the default exploration bounds.
+    pub fn new() -> Self {
+        Self::with_config(ModelCheckerConfig::default())
+    }
+
+    /// Create a model checker with explicit exploration bounds.
+    pub fn with_config(config: ModelCheckerConfig) -> Self {
+        Self {
+            properties: Vec::new(),
+            config,
+            state: PhantomData,
+        }
+    }
+
+    /// Add an invariant to check at every reached state.
+    pub fn add_property<P: Property + Send + Sync + 'static>(&mut self, property: P) {
+        self.properties.push(Box::new(PropertyAdapter::<P, S> {
+            property,
+            state: PhantomData,
+        }));
+    }
+
+    /// Explore the state space reachable from `initial` via `transition`,
+    /// checking all registered properties at every state.
+    ///
+    /// Returns the exploration statistics on success, or
+    /// [`VerificationError::PropertyViolation`] with a reconstructed
+    /// counterexample on the first violation found.
+    pub fn check(
+        &self,
+        initial: &S,
+        transition: &dyn Transition<S>,
+    ) -> VerificationResult<ModelCheckStats> {
+        let init_hash = transition.hash_state(initial);
+
+        let mut visited: HashSet<u64> = HashSet::new();
+        visited.insert(init_hash);
+
+        // Parent pointers for counterexample reconstruction, keyed by the
+        // hash of the child state.
+        let mut parents: std::collections::HashMap<u64, (u64, S)> = std::collections::HashMap::new();
+
+        let mut queue: VecDeque<(S, usize)> = VecDeque::new();
+        queue.push_back((initial.clone(), 0));
+
+        let mut states_explored: u64 = 0;
+        let mut max_queue_size: u64 = 0;
+
+        while let Some((state, depth)) = queue.pop_front() {
+            states_explored += 1;
+
+            for property in &self.properties {
+                if !property.check_any(&state) {
+                    let hash = transition.hash_state(&state);
+                    let counterexample = Self::render_counterexample(&parents, hash, state.clone());
+                    return Err(VerificationError::PropertyViolation {
+                        property: property.name().to_string(),
+                        counterexample,
+                    });
+                }
+            }
+
+            if depth >= self.config.max_depth || visited.len() >= self.config.max_states {
+                continue;
+            }
+
+            for successor in transition.successors(&state) {
+                let hash = transition.hash_state(&successor);
+                if visited.insert(hash) {
+                    parents.insert(hash, (transition.hash_state(&state), state.clone()));
+                    queue.push_back((successor, depth + 1));
+                }
+            }
+
+            max_queue_size = max_queue_size.max(queue.len() as u64);
+
+            if visited.len() >= self.config.max_states {
+                break;
+            }
+        }
+
+        Ok(ModelCheckStats {
+            states_explored,
+            distinct_states: visited.len() as u64,
+            max_queue_size,
+        })
+    }
+
+    /// Walks `parents` back from `hash` to the initial state (which has no
+    /// entry), then renders the path from initial to `final_state` in
+    /// order.
+    fn render_counterexample(
+        parents: &std::collections::HashMap<u64, (u64, S)>,
+        mut hash: u64,
+        final_state: S,
+    ) -> String {
+        let mut path = vec![final_state];
+
+        while let Some((parent_hash, parent_state)) = parents.get(&hash) {
+            path.push(parent_state.clone());
+            hash = *parent_hash;
+        }
+        path.reverse();
+
+        path.iter()
+            .map(|s| {
+                format!(
+                    "(epoch={}, threads={}, garbage={})",
+                    s.current_epoch(),
+                    s.active_threads(),
+                    s.garbage_count()
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+impl<S: VerifiableState + Clone + 'static> Default for ModelChecker<S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // ============================================================================
 // TLA+ Integration Stubs
 // ============================================================================
@@ -405,6 +943,238 @@ impl TlaSpec {
             },
         }
     }
+
+    /// Runs the TLC model checker against `self.module_path` and parses its
+    /// output into [`TlaStats`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VerificationError::ModelCheckingFailure`] if `tlc` can't be
+    /// launched, reports an error, or exits with a nonzero status,
+    /// [`VerificationError::Timeout`] if it doesn't finish within
+    /// `config.timeout`, and [`VerificationError::PropertyViolation`] if
+    /// its output reports a violated invariant, with the violated
+    /// invariant's name and its numbered error trace as the counterexample.
+    pub fn model_check(&self, config: &TlcConfig) -> VerificationResult<TlaStats> {
+        let mut command = std::process::Command::new(&config.tlc_path);
+        command.arg(&self.module_path);
+        if let Some(cfg_path) = &config.config_path {
+            command.arg("-config").arg(cfg_path);
+        }
+
+        let mut child = command
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .spawn()
+            .map_err(|e| VerificationError::ModelCheckingFailure {
+                reason: format!("failed to launch `{}`: {e}", config.tlc_path),
+            })?;
+
+        // Drain stdout on a background thread so a verbose run can't
+        // deadlock by filling the pipe buffer while we're busy polling for
+        // exit below.
+        let mut stdout_pipe = child.stdout.take();
+        let reader = std::thread::spawn(move || {
+            let mut buf = String::new();
+            if let Some(pipe) = stdout_pipe.as_mut() {
+                let _ = std::io::Read::read_to_string(pipe, &mut buf);
+            }
+            buf
+        });
+
+        let start = std::time::Instant::now();
+        let status = loop {
+            let polled = child
+                .try_wait()
+                .map_err(|e| VerificationError::ModelCheckingFailure {
+                    reason: format!("failed to poll tlc: {e}"),
+                })?;
+
+            if let Some(status) = polled {
+                break status;
+            }
+
+            if start.elapsed() >= config.timeout {
+                let _ = child.kill();
+                let _ = child.wait();
+                let _ = reader.join();
+                return Err(VerificationError::Timeout {
+                    timeout_ms: config.timeout.as_millis() as u64,
+                });
+            }
+
+            std::thread::sleep(std::time::Duration::from_millis(25));
+        };
+
+        let stdout = reader.join().unwrap_or_default();
+        let stats = Self::parse_tlc_output(&stdout)?;
+
+        if !status.success() {
+            return Err(VerificationError::ModelCheckingFailure {
+                reason: format!("tlc exited with {status}"),
+            });
+        }
+
+        Ok(stats)
+    }
+
+    /// Parses TLC's stdout into [`TlaStats`], or the appropriate
+    /// [`VerificationError`] if it reports a violation or failure.
+    fn parse_tlc_output(stdout: &str) -> VerificationResult<TlaStats> {
+        for line in stdout.lines() {
+            if line.contains("Invariant") && line.contains("is violated") {
+                return Err(VerificationError::PropertyViolation {
+                    property: Self::extract_invariant_name(line),
+                    counterexample: Self::render_tlc_counterexample(stdout),
+                });
+            }
+
+            if line.trim_start().starts_with("Error:") {
+                return Err(VerificationError::ModelCheckingFailure {
+                    reason: line.trim().to_string(),
+                });
+            }
+        }
+
+        let mut stats = TlaStats::default();
+        for line in stdout.lines() {
+            // TLC's real progress/summary line packs all three counts onto
+            // one line ("N states generated, M distinct states found, K
+            // states left on queue."), so these must be independent `if`s
+            // rather than an `if`/`else if` chain — otherwise the queue
+            // count's branch would never run.
+            if line.contains("states generated") {
+                let numbers = Self::leading_numbers(line);
+                if let Some(&generated) = numbers.first() {
+                    stats.states_explored = generated;
+                }
+                if let Some(&distinct) = numbers.get(1) {
+                    stats.distinct_states = distinct;
+                }
+            }
+
+            if line.contains("states left on queue") {
+                // The queue count is always the last number on the line,
+                // whether it shares the line with the generated/distinct
+                // counts or stands alone.
+                if let Some(&queue) = Self::leading_numbers(line).last() {
+                    stats.max_queue_size = stats.max_queue_size.max(queue);
+                }
+            }
+
+            if line.trim_start().starts_with("Finished in") {
+                if let Some(seconds) = Self::parse_tlc_duration(line) {
+                    stats.time_seconds = seconds;
+                }
+            }
+        }
+
+        Ok(stats)
+    }
+
+    /// Parses the duration out of a TLC `Finished in ...` line, which may
+    /// render as plain seconds (`Finished in 42s at ...`) or as a
+    /// hours/minutes/seconds breakdown (`Finished in 1h 2min 15s at
+    /// ...`). Sums every recognized `<number><unit>` token immediately
+    /// after "Finished in", stopping at the first token that isn't one.
+    fn parse_tlc_duration(line: &str) -> Option<f64> {
+        let mut total = 0.0;
+        let mut found = false;
+
+        for token in line.split_whitespace().skip(2) {
+            let (value, unit_seconds) = if let Some(v) = token.strip_suffix("min") {
+                (v, 60.0)
+            } else if let Some(v) = token.strip_suffix('h') {
+                (v, 3600.0)
+            } else if let Some(v) = token.strip_suffix('s') {
+                (v, 1.0)
+            } else {
+                break;
+            };
+
+            match value.parse::<f64>() {
+                Ok(n) => {
+                    total += n * unit_seconds;
+                    found = true;
+                }
+                Err(_) => break,
+            }
+        }
+
+        found.then_some(total)
+    }
+
+    /// Extracts every run of ASCII digits in `line` as `u64`s, in order.
+    fn leading_numbers(line: &str) -> Vec<u64> {
+        let mut numbers = Vec::new();
+        let mut current = String::new();
+
+        for ch in line.chars() {
+            if ch.is_ascii_digit() {
+                current.push(ch);
+            } else if !current.is_empty() {
+                if let Ok(n) = current.parse() {
+                    numbers.push(n);
+                }
+                current.clear();
+            }
+        }
+        if !current.is_empty() {
+            if let Ok(n) = current.parse() {
+                numbers.push(n);
+            }
+        }
+
+        numbers
+    }
+
+    /// Extracts `X` from a TLC line of the form `Invariant X is violated.`,
+    /// falling back to the whole trimmed line if it doesn't match.
+    fn extract_invariant_name(line: &str) -> String {
+        line.trim()
+            .strip_prefix("Invariant ")
+            .and_then(|rest| rest.split(" is violated").next())
+            .unwrap_or_else(|| line.trim())
+            .trim()
+            .to_string()
+    }
+
+    /// Joins TLC's numbered error-trace (`State N: ...`) lines into a
+    /// single counterexample string, in the same "s1 -> s2 -> ..." shape
+    /// used by [`ModelChecker::render_counterexample`].
+    fn render_tlc_counterexample(stdout: &str) -> String {
+        stdout
+            .lines()
+            .map(str::trim)
+            .filter(|line| {
+                line.strip_prefix("State ")
+                    .and_then(|rest| rest.split(':').next())
+                    .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+            })
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+}
+
+/// Configuration for invoking the TLC model checker via [`TlaSpec::model_check`].
+#[derive(Debug, Clone)]
+pub struct TlcConfig {
+    /// Path to the `tlc` executable (or a launcher script).
+    pub tlc_path: String,
+    /// Optional `.cfg` file to pass alongside the spec.
+    pub config_path: Option<String>,
+    /// Wall-clock timeout for the model-checking run.
+    pub timeout: std::time::Duration,
+}
+
+impl Default for TlcConfig {
+    fn default() -> Self {
+        Self {
+            tlc_path: "tlc".to_string(),
+            config_path: None,
+            timeout: std::time::Duration::from_secs(600),
+        }
+    }
 }
 
 // ============================================================================
@@ -465,11 +1235,318 @@ mod tests {
         assert_eq!(witnesses.len(), 3);
         assert!(witnesses.iter().all(|w| w.verified));
     }
-    
+
+    #[test]
+    fn test_bulk_strategy_returns_witness_per_property() {
+        let mut engine = VerificationEngine::<MockState>::new();
+        engine.set_strategy(VerificationStrategy::Bulk);
+        engine.add_property(NoUseAfterFree);
+        engine.add_property(NoDoubleFree);
+
+        let state = MockState {
+            epoch: 5,
+            threads: 8,
+            garbage: 500,
+        };
+
+        let witnesses = engine.verify(&state).unwrap();
+        assert_eq!(witnesses.len(), 2);
+        assert!(witnesses.iter().all(|w| w.verified));
+    }
+
+    #[test]
+    fn test_bulk_strategy_counterexample_reflects_the_failing_state() {
+        let mut engine = VerificationEngine::<MockState>::new();
+        engine.set_strategy(VerificationStrategy::Bulk);
+        engine.add_property(BoundedGarbage { per_thread_bound: 10 });
+
+        let state = MockState {
+            epoch: 5,
+            threads: 2,
+            garbage: 1_000,
+        };
+
+        let err = engine.verify(&state).unwrap_err();
+        match err {
+            VerificationError::PropertyViolation { property, counterexample } => {
+                assert_eq!(property, "BoundedGarbage");
+                assert_eq!(counterexample, "(epoch=5, threads=2, garbage=1000)");
+            }
+            other => panic!("expected PropertyViolation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_bulk_strategy_counts_fallback_pass_in_properties_checked() {
+        let mut engine = VerificationEngine::<MockState>::new();
+        engine.set_strategy(VerificationStrategy::Bulk);
+        engine.add_property(NoUseAfterFree);
+        engine.add_property(BoundedGarbage { per_thread_bound: 10 });
+
+        let state = MockState {
+            epoch: 5,
+            threads: 2,
+            garbage: 1_000,
+        };
+
+        let _ = engine.verify(&state).unwrap_err();
+
+        // The fused pass checks both properties (2), then the localization
+        // fallback re-checks them one at a time until it finds the violator
+        // (2 more) — both passes' work should be counted.
+        assert_eq!(
+            engine.stats().properties_checked.load(Ordering::Relaxed),
+            4
+        );
+    }
+
+    #[test]
+    fn test_verify_parallel_covers_every_state_in_order() {
+        let mut engine = VerificationEngine::<MockState>::new();
+        engine.add_property(NoUseAfterFree);
+        engine.add_property(BoundedGarbage { per_thread_bound: 100 });
+
+        let states: Vec<MockState> = (0..20)
+            .map(|epoch| MockState {
+                epoch,
+                threads: 4,
+                garbage: 10,
+            })
+            .collect();
+
+        let results = engine.verify_parallel_with_threads(&states, 4).unwrap();
+
+        assert_eq!(results.len(), states.len());
+        for witnesses in &results {
+            assert_eq!(witnesses.len(), 2);
+            assert!(witnesses.iter().all(|w| w.verified));
+        }
+        assert_eq!(
+            engine.stats().states_explored.load(Ordering::Relaxed),
+            states.len() as u64
+        );
+    }
+
+    #[derive(Clone)]
+    struct CounterState {
+        value: u64,
+    }
+
+    impl VerifiableState for CounterState {
+        fn current_epoch(&self) -> u64 {
+            self.value
+        }
+
+        fn active_threads(&self) -> usize {
+            1
+        }
+
+        fn garbage_count(&self) -> usize {
+            0
+        }
+    }
+
+    /// Counts up one at a time from the initial state until `max`.
+    struct IncrementBy1 {
+        max: u64,
+    }
+
+    impl Transition<CounterState> for IncrementBy1 {
+        fn successors(&self, state: &CounterState) -> Vec<CounterState> {
+            if state.value >= self.max {
+                Vec::new()
+            } else {
+                vec![CounterState { value: state.value + 1 }]
+            }
+        }
+
+        fn hash_state(&self, state: &CounterState) -> u64 {
+            state.value
+        }
+    }
+
+    #[test]
+    fn test_model_checker_explores_linear_chain() {
+        let mut checker = ModelChecker::<CounterState>::new();
+        checker.add_property(BoundedGarbage { per_thread_bound: 100 });
+
+        let stats = checker
+            .check(&CounterState { value: 0 }, &IncrementBy1 { max: 9 })
+            .unwrap();
+
+        assert_eq!(stats.states_explored, 10);
+        assert_eq!(stats.distinct_states, 10);
+    }
+
+    #[test]
+    fn test_model_checker_dedups_converging_paths() {
+        // A diamond: 0 -> {1, 2} -> 3. Both branches converge on 3, which
+        // must be visited (and counted) only once.
+        struct Diamond;
+
+        impl Transition<CounterState> for Diamond {
+            fn successors(&self, state: &CounterState) -> Vec<CounterState> {
+                match state.value {
+                    0 => vec![CounterState { value: 1 }, CounterState { value: 2 }],
+                    1 | 2 => vec![CounterState { value: 3 }],
+                    _ => Vec::new(),
+                }
+            }
+
+            fn hash_state(&self, state: &CounterState) -> u64 {
+                state.value
+            }
+        }
+
+        let mut checker = ModelChecker::<CounterState>::new();
+        checker.add_property(BoundedGarbage { per_thread_bound: 100 });
+
+        let stats = checker.check(&CounterState { value: 0 }, &Diamond).unwrap();
+
+        assert_eq!(stats.states_explored, 4);
+        assert_eq!(stats.distinct_states, 4);
+    }
+
+    #[test]
+    fn test_model_checker_respects_max_depth() {
+        let mut checker = ModelChecker::<CounterState>::with_config(ModelCheckerConfig {
+            max_depth: 2,
+            max_states: 1_000_000,
+        });
+        checker.add_property(BoundedGarbage { per_thread_bound: 100 });
+
+        let stats = checker
+            .check(&CounterState { value: 0 }, &IncrementBy1 { max: 100 })
+            .unwrap();
+
+        // Only depths 0, 1, and 2 are ever reached: depth 2 is explored
+        // (checked) but not expanded further.
+        assert_eq!(stats.states_explored, 3);
+        assert_eq!(stats.distinct_states, 3);
+    }
+
+    #[test]
+    fn test_check_any_downcasts_and_checks_real_state() {
+        let mut engine = VerificationEngine::<MockState>::new();
+        engine.add_property(BoundedGarbage { per_thread_bound: 10 });
+
+        let ok_state = MockState {
+            epoch: 1,
+            threads: 2,
+            garbage: 5,
+        };
+        let witnesses = engine.verify(&ok_state).unwrap();
+        assert!(witnesses[0].verified);
+
+        let violating_state = MockState {
+            epoch: 1,
+            threads: 2,
+            garbage: 1_000_000,
+        };
+        let witnesses = engine.verify(&violating_state).unwrap();
+        assert!(!witnesses[0].verified);
+    }
+
+    #[test]
+    fn test_fuzz_finds_and_shrinks_violation() {
+        let mut engine = VerificationEngine::<GeneratedState>::new();
+        engine.add_property(BoundedGarbage { per_thread_bound: 10 });
+
+        // Every generated state violates BoundedGarbage outright (threads=1,
+        // garbage=10_000 vs. a bound of 1 * 10 * 4 = 40), so fuzz should
+        // report it on the very first iteration and shrink it down.
+        let mut generator = || StateBuilder::new().threads(1).garbage(10_000).build();
+
+        let err = engine.fuzz(&mut generator, 50).unwrap_err();
+        match err {
+            VerificationError::PropertyViolation { property, counterexample } => {
+                assert_eq!(property, "BoundedGarbage");
+                // Shrinking drives threads to 0 (a bound of 0, so any
+                // garbage still violates) then garbage down to the smallest
+                // value that still exceeds that bound: 1.
+                assert!(counterexample.contains("threads=0"));
+                assert!(counterexample.contains("garbage=1"));
+            }
+            other => panic!("expected a property violation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_fuzz_succeeds_when_no_violation_found() {
+        let mut engine = VerificationEngine::<GeneratedState>::new();
+        engine.add_property(BoundedGarbage { per_thread_bound: 100 });
+
+        let mut generator = || StateBuilder::new().threads(4).garbage(10).build();
+
+        let witness = engine.fuzz(&mut generator, 20).unwrap();
+        assert_eq!(witness.method, VerificationMethod::PropertyTest);
+        assert!(witness.verified);
+    }
+
     #[test]
     fn test_tla_spec_reference() {
         let spec = TlaSpec::epoch_reclamation();
         assert_eq!(spec.name, "EpochReclamation");
         assert!(spec.stats.states_explored > 10_000_000);
     }
+
+    #[test]
+    fn test_parse_tlc_output_success() {
+        let stdout = "\
+TLC2 Version 2.18
+123456 states generated, 78901 distinct states found, 8472 states left on queue.
+Finished in 42s at (...)
+Model checking completed. No error has been found.
+";
+
+        let stats = TlaSpec::parse_tlc_output(stdout).unwrap();
+        assert_eq!(stats.states_explored, 123456);
+        assert_eq!(stats.distinct_states, 78901);
+        assert_eq!(stats.max_queue_size, 8472);
+        assert_eq!(stats.time_seconds, 42.0);
+    }
+
+    #[test]
+    fn test_parse_tlc_output_minutes_seconds_duration() {
+        let stdout = "\
+123456 states generated, 78901 distinct states found, 0 states left on queue.
+Finished in 2min 15s at (...)
+Model checking completed. No error has been found.
+";
+
+        let stats = TlaSpec::parse_tlc_output(stdout).unwrap();
+        assert_eq!(stats.time_seconds, 135.0);
+    }
+
+    #[test]
+    fn test_parse_tlc_output_invariant_violation() {
+        let stdout = "\
+Invariant NoDoubleFree is violated.
+The behavior up to this point is:
+State 1: <Initial predicate>
+/\\ epoch = 0
+
+State 2: <Retire line 12, col 5>
+/\\ epoch = 1
+";
+
+        let err = TlaSpec::parse_tlc_output(stdout).unwrap_err();
+        match err {
+            VerificationError::PropertyViolation { property, counterexample } => {
+                assert_eq!(property, "NoDoubleFree");
+                assert_eq!(
+                    counterexample,
+                    "State 1: <Initial predicate> -> State 2: <Retire line 12, col 5>"
+                );
+            }
+            other => panic!("expected PropertyViolation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_tlc_config_default() {
+        let config = TlcConfig::default();
+        assert_eq!(config.tlc_path, "tlc");
+        assert!(config.config_path.is_none());
+    }
 }